@@ -1,8 +1,78 @@
 //! Decompositions.
 
-use format::{Conventional, Diagonal};
+use format::packed::Variant;
+use format::{Conventional, Diagonal, Packed};
 use {Element, Result};
 
+/// The Cholesky decomposition.
+pub trait Cholesky<T: Element> {
+    /// Perform the decomposition.
+    ///
+    /// The receiver is assumed to be symmetric positive definite. In the case
+    /// of a conventional matrix, only the triangle corresponding to the
+    /// requested variant is referenced; in the case of a packed matrix, the
+    /// stored triangle is. The function returns a lower-triangular matrix `L`
+    /// such that the original matrix is `L × Lᵀ` when `variant = Lower` or an
+    /// upper-triangular matrix `U` such that the original matrix is `Uᵀ × U`
+    /// when `variant = Upper`.
+    fn decompose(&self, variant: Variant) -> Result<Packed<T>>;
+}
+
+/// The eigendecomposition for general matrices.
+///
+/// The type parameter is the complex counterpart of the element type of the
+/// receiver, since the eigenvalues and eigenvectors of a real matrix are
+/// complex in general.
+pub trait GeneralEigen<T: Element> {
+    /// Perform the decomposition.
+    ///
+    /// The function returns the eigenvalues and the right eigenvectors, which
+    /// are stored as columns. Complex eigenvalues come in conjugate pairs with
+    /// the one with the positive imaginary part first. Each eigenvector is
+    /// normalized to have unit Euclidean norm and its largest component real.
+    fn decompose(&self) -> Result<(Diagonal<T>, Conventional<T>)>;
+
+    /// Perform the decomposition computing also the left eigenvectors.
+    ///
+    /// The function additionally returns the left eigenvectors, which satisfy
+    /// `uᴴ × A = λ × uᴴ` and are normalized in the same way as the right ones.
+    fn decompose_with_left(&self) -> Result<(Diagonal<T>, Conventional<T>, Conventional<T>)>;
+}
+
+/// The LU decomposition with partial pivoting.
+pub trait LU<T: Element> {
+    /// Perform the decomposition.
+    ///
+    /// The function returns a lower-triangular matrix with ones on the
+    /// diagonal, an upper-triangular matrix, and a permutation such that the
+    /// `i`th row of the product of the two matrices is the `permutation[i]`th
+    /// row of the original matrix.
+    fn decompose(&self) -> Result<(Conventional<T>, Conventional<T>, Vec<usize>)>;
+}
+
+/// The QR decomposition.
+pub trait QR<T: Element> {
+    /// Perform the decomposition.
+    ///
+    /// In the economy mode, an `m × n` matrix is decomposed into an `m × k`
+    /// matrix with orthonormal columns and an upper-triangular `k × n` matrix
+    /// where `k = min(m, n)`. In the full mode, the two matrices are `m × m`
+    /// and `m × n`, respectively.
+    fn decompose(&self, mode: Mode) -> Result<(Conventional<T>, Conventional<T>)>;
+
+    /// Perform the decomposition with column pivoting.
+    ///
+    /// The function additionally returns a permutation such that the `j`th
+    /// column of the product of the two matrices is the `permutation[j]`th
+    /// column of the original matrix. The magnitudes of the diagonal elements
+    /// of the upper-triangular matrix are nonincreasing, which makes the
+    /// decomposition rank revealing.
+    fn decompose_pivoted(
+        &self,
+        mode: Mode,
+    ) -> Result<(Conventional<T>, Conventional<T>, Vec<usize>)>;
+}
+
 /// The singular-value decomposition.
 pub trait SingularValue<T: Element> {
     /// Perform the decomposition.
@@ -14,3 +84,12 @@ pub trait SymmetricEigen<T: Element> {
     /// Perform the decomposition.
     fn decompose(&self) -> Result<(Conventional<T>, Diagonal<T>)>;
 }
+
+/// A mode of a decomposition.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Mode {
+    /// The economy mode, which computes only the essential part.
+    Economy,
+    /// The full mode.
+    Full,
+}
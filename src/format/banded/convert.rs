@@ -1,4 +1,4 @@
-use format::{Banded, Conventional, Diagonal};
+use format::{Banded, Compressed, Conventional, Diagonal};
 use Element;
 
 impl<'l, T: Element> From<&'l Banded<T>> for Conventional<T> {
@@ -29,6 +29,32 @@ impl<T: Element> From<Banded<T>> for Conventional<T> {
     }
 }
 
+impl<'l, T: Element> From<&'l Compressed<T>> for Banded<T> {
+    fn from(matrix: &'l Compressed<T>) -> Self {
+        let profile = validate!(matrix).profile();
+        let (superdiagonals, subdiagonals) = (profile.superdiagonals, profile.subdiagonals);
+        let diagonals = superdiagonals + 1 + subdiagonals;
+        let mut values = vec![T::zero(); diagonals * matrix.columns];
+        for (i, j, &value) in matrix.iter() {
+            values[j * diagonals + superdiagonals + i - j] = value;
+        }
+        new!(
+            matrix.rows,
+            matrix.columns,
+            superdiagonals,
+            subdiagonals,
+            values
+        )
+    }
+}
+
+impl<T: Element> From<Compressed<T>> for Banded<T> {
+    #[inline]
+    fn from(matrix: Compressed<T>) -> Self {
+        (&matrix).into()
+    }
+}
+
 impl<'l, T: Element> From<&'l Diagonal<T>> for Banded<T> {
     #[inline]
     fn from(matrix: &'l Diagonal<T>) -> Self {
@@ -54,6 +80,23 @@ impl<T: Element> From<Diagonal<T>> for Banded<T> {
 mod tests {
     use prelude::*;
 
+    #[test]
+    fn from_compressed() {
+        let dense = Conventional::from_vec(
+            (4, 3),
+            matrix![
+                1.0, 2.0, 0.0;
+                0.0, 3.0, 0.0;
+                0.0, 4.0, 5.0;
+                0.0, 0.0, 6.0;
+            ],
+        );
+        let matrix = Banded::from(Compressed::from(&dense));
+        assert_eq!(matrix.superdiagonals, 1);
+        assert_eq!(matrix.subdiagonals, 1);
+        assert_eq!(Conventional::from(matrix), dense);
+    }
+
     #[test]
     fn from_diagonal_tall() {
         let matrix = Banded::from(Diagonal::from_vec((5, 3), vec![1.0, 2.0, 3.0]));
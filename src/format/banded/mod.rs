@@ -9,7 +9,7 @@
 
 use std::iter;
 
-use {Element, Matrix, Size};
+use {Element, Matrix, Result, Size};
 
 /// A banded matrix.
 #[derive(Clone, Debug, PartialEq)]
@@ -83,16 +83,38 @@ pub struct Iterator<'l, T: 'l + Element> {
     finish: usize,
 }
 
-#[cfg(debug_assertions)]
-impl<T: Element> ::format::Validate for Banded<T> {
-    fn validate(&self) {
-        assert_eq!(self.values.len(), self.diagonals() * self.columns);
-    }
-}
-
 size!(Banded);
 
 impl<T: Element> Banded<T> {
+    /// Check that the matrix is valid.
+    ///
+    /// The band should fit within the matrix, and the storage should hold
+    /// exactly one column of the band for each column of the matrix.
+    pub fn validate(&self) -> Result<()> {
+        if self.rows > 0 && self.subdiagonals >= self.rows {
+            raise!(
+                "expected fewer than {} subdiagonals but got {}",
+                self.rows,
+                self.subdiagonals
+            );
+        }
+        if self.columns > 0 && self.superdiagonals >= self.columns {
+            raise!(
+                "expected fewer than {} superdiagonals but got {}",
+                self.columns,
+                self.superdiagonals
+            );
+        }
+        if self.values.len() != self.diagonals() * self.columns {
+            raise!(
+                "expected {} values but got {}",
+                self.diagonals() * self.columns,
+                self.values.len()
+            );
+        }
+        Ok(())
+    }
+
     /// Create a zero matrix.
     pub fn new<S: Size>(size: S, superdiagonals: usize, subdiagonals: usize) -> Self {
         let (rows, columns) = size.dimensions();
@@ -269,4 +291,14 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn validate() {
+        let mut matrix = Banded::<f64>::new((4, 3), 1, 2);
+        assert!(matrix.validate().is_ok());
+        matrix.values.pop();
+        assert!(matrix.validate().is_err());
+        let matrix = Banded::<f64>::new((4, 3), 3, 0);
+        assert!(matrix.validate().is_err());
+    }
 }
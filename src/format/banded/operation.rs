@@ -1,6 +1,87 @@
-use format::Banded;
-use operation::Transpose;
-use Element;
+use std::ops::{Add, Sub};
+
+use format::{Banded, Diagonal};
+use operation::{MultiplyInto, Transpose};
+use {Element, Number};
+
+macro_rules! implement(
+    ($operator:ident, $function:ident, $verb:expr) => (
+        impl<'l, 'r, T> $operator<&'r Banded<T>> for &'l Banded<T>
+        where
+            T: Element + Number,
+        {
+            type Output = Banded<T>;
+
+            #[inline]
+            fn $function(self, right: &'r Banded<T>) -> Self::Output {
+                conform!(self, right, $verb);
+                combine(self, right, <T as $operator>::$function)
+            }
+        }
+
+        impl<'l, 'r, T> $operator<&'r Diagonal<T>> for &'l Banded<T>
+        where
+            T: Element + Number,
+        {
+            type Output = Banded<T>;
+
+            #[inline]
+            fn $function(self, right: &'r Diagonal<T>) -> Self::Output {
+                conform!(self, right, $verb);
+                combine(self, &Banded::from(right), <T as $operator>::$function)
+            }
+        }
+
+        impl<'l, 'r, T> $operator<&'r Banded<T>> for &'l Diagonal<T>
+        where
+            T: Element + Number,
+        {
+            type Output = Banded<T>;
+
+            #[inline]
+            fn $function(self, right: &'r Banded<T>) -> Self::Output {
+                conform!(self, right, $verb);
+                combine(&Banded::from(self), right, <T as $operator>::$function)
+            }
+        }
+
+        forward!($operator, $function, Banded, Banded, Banded);
+        forward!($operator, $function, Banded, Diagonal, Banded);
+        forward!($operator, $function, Diagonal, Banded, Banded);
+    );
+);
+
+implement!(Add, add, "add");
+implement!(Sub, sub, "subtract");
+
+scalar!(Banded);
+
+impl<T> MultiplyInto<[T], [T]> for Banded<T>
+where
+    T: Element + Number,
+{
+    fn multiply_into(&self, right: &[T], result: &mut [T]) {
+        let &Banded {
+            rows: m,
+            columns: p,
+            superdiagonals,
+            subdiagonals,
+            ref values,
+        } = self;
+        let n = multiplicand!(m, p, right, result);
+        let diagonals = self.diagonals();
+        for k in 0..n {
+            let (right, result) = (&right[(k * p)..], &mut result[(k * m)..]);
+            for j in 0..p {
+                let factor = right[j];
+                for i in row_range!(m, superdiagonals, subdiagonals, j) {
+                    let value = values[j * diagonals + superdiagonals + i - j];
+                    result[i] = result[i] + value * factor;
+                }
+            }
+        }
+    }
+}
 
 impl<T: Element> Transpose for Banded<T> {
     fn transpose(&self) -> Self {
@@ -24,10 +105,79 @@ impl<T: Element> Transpose for Banded<T> {
     }
 }
 
+/// Combine two matrices of the same size element by element.
+///
+/// The result has as many superdiagonals and subdiagonals as the wider of the
+/// two operands.
+fn combine<T, F>(left: &Banded<T>, right: &Banded<T>, operation: F) -> Banded<T>
+where
+    T: Element + Number,
+    F: Fn(T, T) -> T,
+{
+    let superdiagonals = if left.superdiagonals > right.superdiagonals {
+        left.superdiagonals
+    } else {
+        right.superdiagonals
+    };
+    let subdiagonals = if left.subdiagonals > right.subdiagonals {
+        left.subdiagonals
+    } else {
+        right.subdiagonals
+    };
+    let mut result = Banded::new((left.rows, left.columns), superdiagonals, subdiagonals);
+    let diagonals = result.diagonals();
+    for (i, j, &value) in left.iter() {
+        result.values[j * diagonals + superdiagonals + i - j] = value;
+    }
+    for (i, j, &value) in right.iter() {
+        let k = j * diagonals + superdiagonals + i - j;
+        result.values[k] = operation(result.values[k], value);
+    }
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use prelude::*;
 
+    #[test]
+    fn add() {
+        let left = new!(
+            3,
+            3,
+            1,
+            0,
+            matrix![
+                0.0, 1.0, 2.0;
+                3.0, 4.0, 5.0;
+            ]
+        );
+        let right = new!(
+            3,
+            3,
+            0,
+            1,
+            matrix![
+                1.0, 1.0, 1.0;
+                2.0, 2.0, 0.0;
+            ]
+        );
+        let result = &left - &right;
+        assert_eq!((result.superdiagonals, result.subdiagonals), (1, 1));
+        assert_eq!(
+            Conventional::from(result),
+            Conventional::from(&left) - Conventional::from(&right)
+        );
+        let result = left + Diagonal::from_vec(3, vec![1.0, 1.0, 1.0]);
+        assert_eq!(&*result.values, &[0.0, 4.0, 1.0, 5.0, 2.0, 6.0]);
+    }
+
+    #[test]
+    fn scale() {
+        let matrix = new!(2, 2, 0, 1, vec![1.0, 2.0, 3.0, 0.0]);
+        assert_eq!(&*(-&matrix * 2.0).values, &[-2.0, -4.0, -6.0, 0.0]);
+    }
+
     #[test]
     fn transpose() {
         let matrix = new!(
@@ -61,4 +211,26 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn multiply_into() {
+        let matrix = new!(
+            4,
+            3,
+            1,
+            2,
+            matrix![
+                0.0, 2.0, 5.0;
+                1.0, 3.0, 6.0;
+                4.0, 7.0, 8.0;
+                9.0, 0.0, 0.0;
+            ]
+        );
+        let right = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let mut result = vec![1.0; 8];
+        matrix.multiply_into(&right[..], &mut result[..]);
+        let expected = Conventional::from(&matrix).multiply(&right[..]);
+        let expected = expected.iter().map(|value| value + 1.0).collect::<Vec<_>>();
+        assert_eq!(result, expected);
+    }
 }
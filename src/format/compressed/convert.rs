@@ -92,6 +92,70 @@ impl<T: Element> From<Diagonal<T>> for Compressed<T> {
     }
 }
 
+impl<T: Element> Compressed<T> {
+    /// Convert the matrix into a given variant.
+    ///
+    /// The conversion is performed by a counting sort, which takes time
+    /// proportional to the number of nonzero elements plus the number of rows
+    /// or columns.
+    pub fn to_variant(&self, variant: Variant) -> Self {
+        if self.variant == variant {
+            return self.clone();
+        }
+        let &Compressed {
+            rows,
+            columns,
+            nonzeros,
+            ref values,
+            ref indices,
+            ref offsets,
+            ..
+        } = validate!(self);
+        let majors = offsets.len() - 1;
+        let minors = match variant {
+            Variant::Column => columns,
+            Variant::Row => rows,
+        };
+        let mut result_offsets = vec![0; minors + 1];
+        for &i in indices {
+            result_offsets[i + 1] += 1;
+        }
+        for i in 0..minors {
+            result_offsets[i + 1] += result_offsets[i];
+        }
+        let mut positions = result_offsets[..minors].to_vec();
+        let mut result_values = vec![T::zero(); nonzeros];
+        let mut result_indices = vec![0; nonzeros];
+        for major in 0..majors {
+            for k in offsets[major]..offsets[major + 1] {
+                let position = &mut positions[indices[k]];
+                result_values[*position] = values[k];
+                result_indices[*position] = major;
+                *position += 1;
+            }
+        }
+        new!(
+            rows,
+            columns,
+            nonzeros,
+            variant,
+            result_values,
+            result_indices,
+            result_offsets
+        )
+    }
+
+    /// Convert the matrix into a given variant consuming the original.
+    #[inline]
+    pub fn into_variant(self, variant: Variant) -> Self {
+        if self.variant == variant {
+            self
+        } else {
+            self.to_variant(variant)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use format::compressed::Variant;
@@ -181,4 +245,32 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn to_variant() {
+        let matrix = new!(
+            5,
+            3,
+            4,
+            Variant::Column,
+            vec![1.0, 2.0, 3.0, 4.0],
+            vec![1, 3, 4, 4],
+            vec![0, 1, 3, 4]
+        );
+        let result = matrix.to_variant(Variant::Row);
+        assert_eq!(
+            result,
+            new!(
+                5,
+                3,
+                4,
+                Variant::Row,
+                vec![1.0, 2.0, 3.0, 4.0],
+                vec![0, 1, 1, 2],
+                vec![0, 0, 1, 1, 2, 4]
+            )
+        );
+        assert_eq!(Conventional::from(&result), Conventional::from(&matrix));
+        assert_eq!(result.into_variant(Variant::Column), matrix);
+    }
 }
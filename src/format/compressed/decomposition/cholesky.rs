@@ -0,0 +1,384 @@
+use num_traits::Float;
+
+use format::compressed::Variant;
+use format::{Compressed, Diagonal};
+use operation::Solve;
+use {Element, Result};
+
+use super::Pattern;
+
+/// The symbolic analysis of the sparse Cholesky decomposition.
+///
+/// The analysis computes the elimination tree of a matrix and the number of
+/// nonzero elements in each column of the factor. Unlike `SymbolicLU`, the
+/// analysis applies no ordering, since a symmetric permutation would need the
+/// lower triangle as well; a fill-reducing ordering can be computed by
+/// `minimum_degree` and applied by `permute_symmetric` beforehand.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SymbolicCholesky {
+    /// The parent of each column in the elimination tree or `None` for roots.
+    pub parents: Vec<Option<usize>>,
+    /// The number of nonzero elements below the diagonal in each column of
+    /// the factor.
+    pub counts: Vec<usize>,
+    pattern: Pattern,
+}
+
+/// The numeric sparse Cholesky decomposition in the LDLᵀ form.
+///
+/// The original matrix is `L × D × Lᵀ` where `L` is a lower-triangular
+/// matrix with ones on the diagonal and `D` is a diagonal matrix. The form
+/// requires no square roots and exists for symmetric positive-definite and
+/// quasi-definite matrices alike.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NumericCholesky<T: Element> {
+    /// The lower-triangular factor with ones on the diagonal in the
+    /// compressed-column variant.
+    pub lower: Compressed<T>,
+    /// The diagonal factor.
+    pub diagonal: Diagonal<T>,
+    symbolic: SymbolicCholesky,
+}
+
+impl SymbolicCholesky {
+    /// Perform the analysis.
+    ///
+    /// The matrix is expected to be square, symmetric, and stored in the
+    /// compressed-column variant; only its upper triangle is referenced.
+    pub fn new<T: Element>(matrix: &Compressed<T>) -> Result<Self> {
+        let pattern = Pattern::new(matrix)?;
+        let n = pattern.size;
+        let mut parents = vec![None; n];
+        let mut counts = vec![0; n];
+        let mut flags = vec![0; n];
+        for k in 0..n {
+            flags[k] = k;
+            for p in matrix.offsets[k]..matrix.offsets[k + 1] {
+                let mut i = matrix.indices[p];
+                if i >= k {
+                    break;
+                }
+                while flags[i] != k {
+                    let parent = *parents[i].get_or_insert(k);
+                    counts[i] += 1;
+                    flags[i] = k;
+                    i = parent;
+                }
+            }
+        }
+        Ok(SymbolicCholesky {
+            parents,
+            counts,
+            pattern,
+        })
+    }
+
+    /// Perform the numeric decomposition.
+    pub fn factorize<T>(&self, matrix: &Compressed<T>) -> Result<NumericCholesky<T>>
+    where
+        T: Element + Float,
+    {
+        let n = self.counts.len();
+        let mut offsets = Vec::with_capacity(n + 1);
+        offsets.push(0);
+        for &count in &self.counts {
+            let offset = offsets[offsets.len() - 1];
+            offsets.push(offset + count + 1);
+        }
+        let nonzeros = offsets[n];
+        let mut lower = new!(
+            n,
+            n,
+            nonzeros,
+            Variant::Column,
+            vec![T::one(); nonzeros],
+            vec![0; nonzeros],
+            offsets
+        );
+        let mut diagonal = Diagonal::from_vec(n, vec![<T as Element>::zero(); n]);
+        self.numeric(matrix, &mut lower, &mut diagonal)?;
+        Ok(NumericCholesky {
+            lower,
+            diagonal,
+            symbolic: self.clone(),
+        })
+    }
+
+    /// Compute the values of the factors.
+    ///
+    /// Each row of the factor is found by traversing the elimination tree
+    /// upward from the nonzero elements of the corresponding column of the
+    /// upper triangle.
+    fn numeric<T>(
+        &self,
+        matrix: &Compressed<T>,
+        lower: &mut Compressed<T>,
+        diagonal: &mut Diagonal<T>,
+    ) -> Result<()>
+    where
+        T: Element + Float,
+    {
+        self.pattern.check(matrix)?;
+        let n = self.counts.len();
+        let zero = <T as Element>::zero();
+        let mut work = vec![zero; n];
+        let mut flags = vec![0; n];
+        let mut lengths = vec![0; n];
+        let mut pattern = vec![0; n];
+        for k in 0..n {
+            let mut top = n;
+            flags[k] = k;
+            for p in matrix.offsets[k]..matrix.offsets[k + 1] {
+                let mut i = matrix.indices[p];
+                if i > k {
+                    break;
+                }
+                work[i] = work[i] + matrix.values[p];
+                let mut length = 0;
+                while flags[i] != k {
+                    pattern[length] = i;
+                    length += 1;
+                    flags[i] = k;
+                    i = self.parents[i].unwrap();
+                }
+                while length > 0 {
+                    length -= 1;
+                    top -= 1;
+                    pattern[top] = pattern[length];
+                }
+            }
+            let mut value = work[k];
+            work[k] = zero;
+            for &i in &pattern[top..] {
+                let y = work[i];
+                work[i] = zero;
+                let start = lower.offsets[i] + 1;
+                let end = start + lengths[i];
+                for p in start..end {
+                    let j = lower.indices[p];
+                    work[j] = work[j] - lower.values[p] * y;
+                }
+                let factor = y / diagonal[i];
+                value = value - factor * y;
+                lower.indices[end] = k;
+                lower.values[end] = factor;
+                lengths[i] += 1;
+            }
+            if Element::is_zero(&value) {
+                raise!(Singular { pivot: k });
+            }
+            let p = lower.offsets[k];
+            lower.indices[p] = k;
+            diagonal[k] = value;
+        }
+        Ok(())
+    }
+}
+
+impl<T> NumericCholesky<T>
+where
+    T: Element + Float,
+{
+    /// Perform the numeric decomposition of another matrix.
+    ///
+    /// The matrix should have the sparsity pattern that has been analyzed.
+    pub fn refactorize(&mut self, matrix: &Compressed<T>) -> Result<()> {
+        let NumericCholesky {
+            ref mut lower,
+            ref mut diagonal,
+            ref symbolic,
+        } = *self;
+        symbolic.numeric(matrix, lower, diagonal)
+    }
+
+    /// Compute the conventional Cholesky factor.
+    ///
+    /// The function returns a lower-triangular matrix `L × √D` in the
+    /// compressed-column variant, which requires the original matrix to be
+    /// positive definite.
+    pub fn factor(&self) -> Result<Compressed<T>> {
+        let mut factor = self.lower.clone();
+        for j in 0..factor.columns {
+            let value = self.diagonal[j];
+            if value <= <T as Element>::zero() {
+                raise!(NotPositiveDefinite { pivot: j });
+            }
+            let value = value.sqrt();
+            for p in factor.offsets[j]..factor.offsets[j + 1] {
+                factor.values[p] = factor.values[p] * value;
+            }
+        }
+        Ok(factor)
+    }
+}
+
+impl<T> Solve<[T], Vec<T>> for NumericCholesky<T>
+where
+    T: Element + Float,
+{
+    fn solve(&self, right: &[T]) -> Result<Vec<T>> {
+        let n = self.lower.columns;
+        if right.len() != n {
+            raise!(DimensionMismatch {
+                left: (n, n),
+                right: (right.len(), 1),
+            });
+        }
+        let lower = &self.lower;
+        let mut result = right.to_vec();
+        for k in 0..n {
+            let value = result[k];
+            for p in (lower.offsets[k] + 1)..lower.offsets[k + 1] {
+                let i = lower.indices[p];
+                result[i] = result[i] - lower.values[p] * value;
+            }
+        }
+        for (value, &diagonal) in result.iter_mut().zip(self.diagonal.iter()) {
+            *value = *value / diagonal;
+        }
+        for k in (0..n).rev() {
+            let mut value = result[k];
+            for p in (lower.offsets[k] + 1)..lower.offsets[k + 1] {
+                value = value - lower.values[p] * result[lower.indices[p]];
+            }
+            result[k] = value;
+        }
+        Ok(result)
+    }
+}
+
+impl<T> Solve<Vec<T>, Vec<T>> for NumericCholesky<T>
+where
+    T: Element + Float,
+{
+    #[inline(always)]
+    fn solve(&self, right: &Vec<T>) -> Result<Vec<T>> {
+        Solve::solve(self, &right[..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use format::compressed::tests::tridiagonal;
+    use format::compressed::{SymbolicCholesky, Variant};
+    use prelude::*;
+    use Error;
+
+    fn reconstruct(lower: &Compressed<f64>, diagonal: &Diagonal<f64>) -> Conventional<f64> {
+        let lower = Conventional::from(lower);
+        let mut scaled = lower.clone();
+        for j in 0..scaled.columns {
+            for i in 0..scaled.rows {
+                scaled[(i, j)] *= diagonal[j];
+            }
+        }
+        scaled.multiply(&lower.transpose())
+    }
+
+    #[test]
+    fn analyze() {
+        let matrix = tridiagonal(4, -1.0, 2.0, -1.0).to_variant(Variant::Column);
+        let symbolic = SymbolicCholesky::new(&matrix).unwrap();
+        assert_eq!(symbolic.parents, vec![Some(1), Some(2), Some(3), None]);
+        assert_eq!(symbolic.counts, vec![1, 1, 1, 0]);
+
+        let matrix = Compressed::from(Conventional::from_vec(
+            4,
+            matrix![
+                4.0, 0.0, 0.0, 1.0;
+                0.0, 4.0, 1.0, 0.0;
+                0.0, 1.0, 4.0, 1.0;
+                1.0, 0.0, 1.0, 4.0;
+            ],
+        ));
+        let symbolic = SymbolicCholesky::new(&matrix).unwrap();
+        assert_eq!(symbolic.parents, vec![Some(3), Some(2), Some(3), None]);
+        assert_eq!(symbolic.counts, vec![1, 1, 1, 0]);
+    }
+
+    #[test]
+    fn factorize() {
+        let dense = Conventional::from_vec(
+            5,
+            matrix![
+                10.0,  1.0,  0.0,  2.0,  0.0;
+                 1.0,  8.0,  0.0,  0.0,  3.0;
+                 0.0,  0.0,  6.0,  1.0,  0.0;
+                 2.0,  0.0,  1.0,  9.0,  1.0;
+                 0.0,  3.0,  0.0,  1.0,  7.0;
+            ],
+        );
+        let matrix = Compressed::from(&dense);
+        let symbolic = SymbolicCholesky::new(&matrix).unwrap();
+        let cholesky = symbolic.factorize(&matrix).unwrap();
+        assert!(cholesky.lower.validate().is_ok());
+        assert_eq!(symbolic.counts, vec![2, 2, 1, 1, 0]);
+        assert_eq!(cholesky.lower.nonzeros, 5 + 6);
+        assert::close(
+            &*reconstruct(&cholesky.lower, &cholesky.diagonal),
+            &*dense,
+            1e-13,
+        );
+        let factor = Conventional::from(cholesky.factor().unwrap());
+        assert::close(&*factor.multiply(&factor.transpose()), &*dense, 1e-13);
+        let expected = vec![1.0, -2.0, 3.0, -4.0, 5.0];
+        let right = dense.multiply(&expected).values;
+        assert::close(cholesky.solve(&right).unwrap(), expected, 1e-13);
+    }
+
+    #[test]
+    fn factorize_quasi_definite() {
+        let dense = Conventional::from_vec(
+            3,
+            matrix![
+                4.0, 0.0,  1.0;
+                0.0, 2.0,  1.0;
+                1.0, 1.0, -3.0;
+            ],
+        );
+        let matrix = Compressed::from(&dense);
+        let symbolic = SymbolicCholesky::new(&matrix).unwrap();
+        let cholesky = symbolic.factorize(&matrix).unwrap();
+        assert!(cholesky.diagonal[2] < 0.0);
+        assert::close(
+            &*reconstruct(&cholesky.lower, &cholesky.diagonal),
+            &*dense,
+            1e-14,
+        );
+        assert_eq!(
+            cholesky.factor(),
+            Err(Error::NotPositiveDefinite { pivot: 2 })
+        );
+        let expected = vec![1.0, 2.0, 3.0];
+        let right = dense.multiply(&expected).values;
+        assert::close(cholesky.solve(&right).unwrap(), expected, 1e-14);
+    }
+
+    #[test]
+    fn refactorize() {
+        let matrix = tridiagonal(10, -1.0, 2.0, -1.0).to_variant(Variant::Column);
+        let symbolic = SymbolicCholesky::new(&matrix).unwrap();
+        let mut cholesky = symbolic.factorize(&matrix).unwrap();
+        let mut matrix = matrix;
+        for (i, j, value) in matrix.iter_mut() {
+            if i == j {
+                *value = 4.0;
+            }
+        }
+        cholesky.refactorize(&matrix).unwrap();
+        assert::close(
+            &*reconstruct(&cholesky.lower, &cholesky.diagonal),
+            &*Conventional::from(&matrix),
+            1e-14,
+        );
+        let other = Compressed::from(Diagonal::from_vec(10, vec![2.0; 10]));
+        assert!(cholesky.refactorize(&other).is_err());
+        let singular = Compressed::from(Diagonal::from_vec(2, vec![1.0, 0.0]));
+        let symbolic = SymbolicCholesky::new(&singular).unwrap();
+        assert_eq!(
+            symbolic.factorize(&singular).map(|_| ()),
+            Err(Error::Singular { pivot: 1 })
+        );
+    }
+}
@@ -0,0 +1,524 @@
+use num_traits::Float;
+
+use format::compressed::Variant;
+use format::Compressed;
+use operation::Solve;
+use {Element, Result};
+
+use super::Pattern;
+
+/// The symbolic analysis of the sparse LU decomposition.
+///
+/// The analysis computes a fill-reducing ordering of the columns by the
+/// approximate minimum-degree algorithm and permutes the sparsity pattern
+/// accordingly. The ordering is applied to the rows as well so that the
+/// diagonal remains the preferred pivot. Unlike `SymbolicCholesky`, the
+/// analysis reorders the matrix itself, and the permutations are reported by
+/// the numeric decomposition.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SymbolicLU {
+    /// The column ordering such that the `j`th column of the permuted matrix
+    /// is the `ordering[j]`th column of the original one.
+    pub ordering: Vec<usize>,
+    pattern: Pattern,
+    permuted: Compressed<usize>,
+}
+
+/// The numeric sparse LU decomposition with threshold partial pivoting.
+///
+/// The decomposition is computed by the left-looking algorithm of Gilbert and
+/// Peierls. The `(i, j)`th element of the product of the two factors is the
+/// `(rows[i], columns[j])`th element of the original matrix.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NumericLU<T: Element> {
+    /// The lower-triangular factor with ones on the diagonal in the
+    /// compressed-column variant.
+    pub lower: Compressed<T>,
+    /// The upper-triangular factor in the compressed-column variant.
+    pub upper: Compressed<T>,
+    /// The row permutation.
+    pub rows: Vec<usize>,
+    /// The column permutation.
+    pub columns: Vec<usize>,
+    pivots: Vec<usize>,
+    symbolic: SymbolicLU,
+    threshold: T,
+}
+
+impl SymbolicLU {
+    /// Perform the analysis.
+    ///
+    /// The matrix is expected to be square and stored in the compressed-column
+    /// variant.
+    pub fn new<T: Element>(matrix: &Compressed<T>) -> Result<Self> {
+        let pattern = Pattern::new(matrix)?;
+        let ordering = matrix.minimum_degree()?;
+        let (n, nonzeros) = (pattern.size, matrix.nonzeros);
+        let permuted = new!(
+            n,
+            n,
+            nonzeros,
+            Variant::Column,
+            (0..nonzeros).collect(),
+            pattern.indices.clone(),
+            pattern.offsets.clone()
+        )
+        .permute_symmetric(&ordering)?;
+        Ok(SymbolicLU {
+            ordering,
+            pattern,
+            permuted,
+        })
+    }
+
+    /// Perform the numeric decomposition.
+    ///
+    /// A candidate pivot on the diagonal is preferred as long as its magnitude
+    /// is at least `threshold` times the largest magnitude in its column,
+    /// which preserves sparsity; `threshold = 1` corresponds to conventional
+    /// partial pivoting.
+    pub fn factorize<T>(&self, matrix: &Compressed<T>, threshold: T) -> Result<NumericLU<T>>
+    where
+        T: Element + Float,
+    {
+        self.pattern.check(matrix)?;
+        if !(threshold > <T as Element>::zero() && threshold <= T::one()) {
+            raise!("expected a pivoting threshold in (0, 1]");
+        }
+        let (n, permuted) = (self.pattern.size, &self.permuted);
+        let zero = <T as Element>::zero();
+        let values = permuted
+            .values
+            .iter()
+            .map(|&p| matrix.values[p])
+            .collect::<Vec<_>>();
+        let (mut lower_values, mut lower_indices) = (vec![], vec![]);
+        let mut lower_offsets = Vec::with_capacity(n + 1);
+        let (mut upper_values, mut upper_indices) = (vec![], vec![]);
+        let mut upper_offsets = Vec::with_capacity(n + 1);
+        lower_offsets.push(0);
+        upper_offsets.push(0);
+        let mut pivots = vec![0; n];
+        let mut inverse = vec![None; n];
+        let mut work = vec![zero; n];
+        let mut stamps = vec![n; n];
+        let mut marked = vec![false; n];
+        let (mut pattern, mut order, mut stack) = (vec![], vec![], vec![]);
+        for j in 0..n {
+            let column = permuted.offsets[j]..permuted.offsets[j + 1];
+            pattern.clear();
+            for p in column.clone() {
+                let i = permuted.indices[p];
+                work[i] = values[p];
+                stamps[i] = j;
+                pattern.push(i);
+            }
+            reach(
+                &lower_offsets,
+                &lower_indices,
+                &inverse,
+                &permuted.indices[column],
+                &mut marked,
+                &mut stack,
+                &mut order,
+            );
+            for &k in order.iter().rev() {
+                marked[k] = false;
+                let value = work[pivots[k]];
+                upper_indices.push(k);
+                upper_values.push(value);
+                for q in (lower_offsets[k] + 1)..lower_offsets[k + 1] {
+                    let i = lower_indices[q];
+                    if stamps[i] != j {
+                        stamps[i] = j;
+                        pattern.push(i);
+                    }
+                    work[i] = work[i] - lower_values[q] * value;
+                }
+            }
+            let (mut pivot, mut maximum) = (None, zero);
+            for &i in &pattern {
+                if inverse[i].is_none() && (pivot.is_none() || work[i].abs() > maximum) {
+                    pivot = Some(i);
+                    maximum = work[i].abs();
+                }
+            }
+            if stamps[j] == j && inverse[j].is_none() && work[j].abs() >= threshold * maximum {
+                pivot = Some(j);
+            }
+            let pivot = match pivot {
+                Some(i) if !Element::is_zero(&work[i]) => i,
+                _ => {
+                    raise!(Singular {
+                        pivot: self.ordering[j],
+                    });
+                }
+            };
+            let value = work[pivot];
+            upper_indices.push(j);
+            upper_values.push(value);
+            upper_offsets.push(upper_indices.len());
+            lower_indices.push(pivot);
+            lower_values.push(T::one());
+            for &i in &pattern {
+                if inverse[i].is_none() && i != pivot {
+                    lower_indices.push(i);
+                    lower_values.push(work[i] / value);
+                }
+                work[i] = zero;
+            }
+            lower_offsets.push(lower_indices.len());
+            inverse[pivot] = Some(j);
+            pivots[j] = pivot;
+        }
+        for index in &mut lower_indices {
+            *index = inverse[*index].unwrap();
+        }
+        sort(&lower_offsets, &mut lower_indices, &mut lower_values);
+        sort(&upper_offsets, &mut upper_indices, &mut upper_values);
+        Ok(NumericLU {
+            lower: new!(
+                n,
+                n,
+                lower_values.len(),
+                Variant::Column,
+                lower_values,
+                lower_indices,
+                lower_offsets
+            ),
+            upper: new!(
+                n,
+                n,
+                upper_values.len(),
+                Variant::Column,
+                upper_values,
+                upper_indices,
+                upper_offsets
+            ),
+            rows: pivots.iter().map(|&i| self.ordering[i]).collect(),
+            columns: self.ordering.clone(),
+            pivots,
+            symbolic: self.clone(),
+            threshold,
+        })
+    }
+}
+
+impl<T> NumericLU<T>
+where
+    T: Element + Float,
+{
+    /// Perform the numeric decomposition of another matrix.
+    ///
+    /// The matrix should have the sparsity pattern that has been analyzed.
+    /// The patterns of the factors and the pivots are reused, which avoids
+    /// all symbolic work. If a reused pivot fails the threshold test, the
+    /// matrix is factorized anew with pivoting.
+    pub fn refactorize(&mut self, matrix: &Compressed<T>) -> Result<()> {
+        self.symbolic.pattern.check(matrix)?;
+        let n = self.symbolic.pattern.size;
+        let zero = <T as Element>::zero();
+        let mut inverse = vec![0; n];
+        for (k, &i) in self.pivots.iter().enumerate() {
+            inverse[i] = k;
+        }
+        let mut work = vec![zero; n];
+        let mut stable = true;
+        {
+            let lower = &mut self.lower;
+            let upper = &mut self.upper;
+            let permuted = &self.symbolic.permuted;
+            for j in 0..n {
+                for p in permuted.offsets[j]..permuted.offsets[j + 1] {
+                    work[inverse[permuted.indices[p]]] = matrix.values[permuted.values[p]];
+                }
+                let last = upper.offsets[j + 1] - 1;
+                for p in upper.offsets[j]..last {
+                    let k = upper.indices[p];
+                    let value = work[k];
+                    upper.values[p] = value;
+                    work[k] = zero;
+                    for q in (lower.offsets[k] + 1)..lower.offsets[k + 1] {
+                        let i = lower.indices[q];
+                        work[i] = work[i] - lower.values[q] * value;
+                    }
+                }
+                let value = work[j];
+                work[j] = zero;
+                let column = (lower.offsets[j] + 1)..lower.offsets[j + 1];
+                let maximum = column
+                    .clone()
+                    .fold(zero, |maximum, q| maximum.max(work[lower.indices[q]].abs()));
+                if Element::is_zero(&value) || value.abs() < self.threshold * maximum {
+                    stable = false;
+                    break;
+                }
+                upper.values[last] = value;
+                for q in column {
+                    let i = lower.indices[q];
+                    lower.values[q] = work[i] / value;
+                    work[i] = zero;
+                }
+            }
+        }
+        if !stable {
+            *self = self.symbolic.factorize(matrix, self.threshold)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Solve<[T], Vec<T>> for NumericLU<T>
+where
+    T: Element + Float,
+{
+    fn solve(&self, right: &[T]) -> Result<Vec<T>> {
+        let n = self.symbolic.pattern.size;
+        if right.len() != n {
+            raise!(DimensionMismatch {
+                left: (n, n),
+                right: (right.len(), 1),
+            });
+        }
+        let mut result = self.rows.iter().map(|&i| right[i]).collect::<Vec<_>>();
+        let (lower, upper) = (&self.lower, &self.upper);
+        for k in 0..n {
+            let value = result[k];
+            for q in (lower.offsets[k] + 1)..lower.offsets[k + 1] {
+                let i = lower.indices[q];
+                result[i] = result[i] - lower.values[q] * value;
+            }
+        }
+        for k in (0..n).rev() {
+            let last = upper.offsets[k + 1] - 1;
+            result[k] = result[k] / upper.values[last];
+            let value = result[k];
+            for q in upper.offsets[k]..last {
+                let i = upper.indices[q];
+                result[i] = result[i] - upper.values[q] * value;
+            }
+        }
+        let mut solution = vec![<T as Element>::zero(); n];
+        for (&j, &value) in self.columns.iter().zip(&result) {
+            solution[j] = value;
+        }
+        Ok(solution)
+    }
+}
+
+impl<T> Solve<Vec<T>, Vec<T>> for NumericLU<T>
+where
+    T: Element + Float,
+{
+    #[inline(always)]
+    fn solve(&self, right: &Vec<T>) -> Result<Vec<T>> {
+        Solve::solve(self, &right[..])
+    }
+}
+
+/// Find the columns of the lower-triangular factor that update a column.
+///
+/// The columns reachable from the pivoted rows of `start` in the graph of the
+/// factor are written to `order` in the postorder of a depth-first search,
+/// which is the reverse of a topological order. The rows of the factor are
+/// in the original numbering, and `inverse` maps them to pivoted columns.
+fn reach(
+    offsets: &[usize],
+    indices: &[usize],
+    inverse: &[Option<usize>],
+    start: &[usize],
+    marked: &mut [bool],
+    stack: &mut Vec<(usize, usize)>,
+    order: &mut Vec<usize>,
+) {
+    order.clear();
+    for &i in start {
+        let k = match inverse[i] {
+            Some(k) if !marked[k] => k,
+            _ => continue,
+        };
+        marked[k] = true;
+        stack.push((k, offsets[k]));
+        while let Some(&(k, mut p)) = stack.last() {
+            let mut next = None;
+            while p < offsets[k + 1] {
+                let i = indices[p];
+                p += 1;
+                match inverse[i] {
+                    Some(c) if !marked[c] => {
+                        next = Some(c);
+                        break;
+                    }
+                    _ => {}
+                }
+            }
+            let last = stack.len() - 1;
+            stack[last].1 = p;
+            match next {
+                Some(c) => {
+                    marked[c] = true;
+                    stack.push((c, offsets[c]));
+                }
+                None => {
+                    stack.pop();
+                    order.push(k);
+                }
+            }
+        }
+    }
+}
+
+/// Sort the indices within each major.
+fn sort<T: Element>(offsets: &[usize], indices: &mut [usize], values: &mut [T]) {
+    let mut pairs = vec![];
+    for i in 0..(offsets.len() - 1) {
+        let range = offsets[i]..offsets[i + 1];
+        pairs.clear();
+        pairs.extend(
+            indices[range.clone()]
+                .iter()
+                .cloned()
+                .zip(values[range.clone()].iter().cloned()),
+        );
+        pairs.sort_unstable_by_key(|pair| pair.0);
+        for (p, (index, value)) in range.zip(pairs.iter().cloned()) {
+            indices[p] = index;
+            values[p] = value;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use format::compressed::{SymbolicLU, Variant};
+    use prelude::*;
+    use Error;
+
+    fn product(lower: &Compressed<f64>, upper: &Compressed<f64>) -> Conventional<f64> {
+        Conventional::from(lower).multiply(&Conventional::from(upper))
+    }
+
+    fn permute(matrix: &Conventional<f64>, rows: &[usize], columns: &[usize]) -> Conventional<f64> {
+        let mut result = matrix.clone();
+        for (i, &k) in rows.iter().enumerate() {
+            for (j, &l) in columns.iter().enumerate() {
+                result[(i, j)] = matrix[(k, l)];
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn analyze() {
+        let mut triplets = vec![(0, 0, 10.0)];
+        for i in 1..6 {
+            triplets.push((i, i, 2.0));
+            triplets.push((0, i, 1.0));
+            triplets.push((i, 0, 1.0));
+        }
+        let matrix = Compressed::from_triplets(6, Variant::Column, triplets).unwrap();
+        let symbolic = SymbolicLU::new(&matrix).unwrap();
+        assert_eq!(symbolic.ordering, matrix.minimum_degree().unwrap());
+        let lu = symbolic.factorize(&matrix, 1.0).unwrap();
+        assert_eq!(lu.columns, symbolic.ordering);
+        assert_eq!(lu.rows, symbolic.ordering);
+        assert_eq!(lu.lower.nonzeros + lu.upper.nonzeros, matrix.nonzeros + 6);
+    }
+
+    #[test]
+    fn factorize() {
+        let dense = Conventional::from_vec(
+            4,
+            matrix![
+                1.0, 0.0, 0.0,  3.0;
+                4.0, 2.0, 0.0,  0.0;
+                0.0, 5.0, 0.0, -1.0;
+                0.0, 0.0, 6.0,  2.0;
+            ],
+        );
+        let matrix = Compressed::from(&dense);
+        let symbolic = SymbolicLU::new(&matrix).unwrap();
+        for &threshold in &[1.0, 0.1] {
+            let lu = symbolic.factorize(&matrix, threshold).unwrap();
+            assert!(lu.lower.validate().is_ok());
+            assert!(lu.upper.validate().is_ok());
+            assert::close(
+                &*product(&lu.lower, &lu.upper),
+                &*permute(&dense, &lu.rows, &lu.columns),
+                1e-14,
+            );
+            let solution = lu.solve(&vec![4.0, 6.0, 4.0, 8.0]).unwrap();
+            assert::close(&solution, &[1.0, 1.0, 1.0, 1.0][..], 1e-14);
+        }
+    }
+
+    #[test]
+    fn refactorize() {
+        let mut dense = Conventional::from_vec(
+            3,
+            matrix![
+                4.0, 1.0, 0.0;
+                1.0, 4.0, 1.0;
+                0.0, 1.0, 4.0;
+            ],
+        );
+        let matrix = Compressed::from(&dense);
+        let symbolic = SymbolicLU::new(&matrix).unwrap();
+        let mut lu = symbolic.factorize(&matrix, 0.5).unwrap();
+        assert_eq!(lu.rows, vec![0, 1, 2]);
+
+        dense[(0, 0)] = 2.0;
+        dense[(2, 2)] = -3.0;
+        lu.refactorize(&Compressed::from(&dense)).unwrap();
+        assert_eq!(lu.rows, vec![0, 1, 2]);
+        assert::close(&*product(&lu.lower, &lu.upper), &*dense, 1e-14);
+
+        dense[(0, 0)] = 0.1;
+        lu.refactorize(&Compressed::from(&dense)).unwrap();
+        assert_eq!(lu.rows, vec![1, 2, 0]);
+        assert::close(
+            &*product(&lu.lower, &lu.upper),
+            &*permute(&dense, &lu.rows, &lu.columns),
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn factorize_singular() {
+        let mut triplets = vec![(0, 0, 4.0)];
+        for i in 1..4 {
+            triplets.push((i, i, 4.0));
+            triplets.push((0, i, 1.0));
+            triplets.push((i, 0, 1.0));
+        }
+        let mut matrix = Compressed::from_triplets(4, Variant::Column, triplets).unwrap();
+        let symbolic = SymbolicLU::new(&matrix).unwrap();
+        assert!(symbolic.ordering != vec![0, 1, 2, 3]);
+        let mut lu = symbolic.factorize(&matrix, 1.0).unwrap();
+        for p in matrix.offsets[1]..matrix.offsets[2] {
+            matrix.values[p] = 0.0;
+        }
+        assert_eq!(
+            symbolic.factorize(&matrix, 1.0).map(|_| ()),
+            Err(Error::Singular { pivot: 1 })
+        );
+        assert_eq!(lu.refactorize(&matrix), Err(Error::Singular { pivot: 1 }));
+    }
+
+    #[test]
+    fn factorize_mismatch() {
+        let matrix = Compressed::from(Diagonal::from_vec(2, vec![1.0, 2.0]));
+        let symbolic = SymbolicLU::new(&matrix).unwrap();
+        let other = Compressed::from(Conventional::from_vec(2, vec![1.0, 1.0, 0.0, 1.0]));
+        assert!(symbolic.factorize(&other, 1.0).is_err());
+        assert!(symbolic.factorize(&matrix, 0.0).is_err());
+        let singular = Compressed::from(Diagonal::from_vec(2, vec![1.0, 0.0]));
+        assert_eq!(
+            symbolic.factorize(&singular, 1.0).map(|_| ()),
+            Err(Error::Singular { pivot: 1 })
+        );
+        let row = matrix.to_variant(Variant::Row);
+        assert!(SymbolicLU::new(&row).is_err());
+    }
+}
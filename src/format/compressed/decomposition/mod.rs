@@ -0,0 +1,49 @@
+use format::compressed::Variant;
+use format::Compressed;
+use {Element, Result};
+
+mod cholesky;
+mod lu;
+
+pub use self::cholesky::{NumericCholesky, SymbolicCholesky};
+pub use self::lu::{NumericLU, SymbolicLU};
+
+/// The sparsity pattern of an analyzed matrix.
+#[derive(Clone, Debug, PartialEq)]
+struct Pattern {
+    size: usize,
+    indices: Vec<usize>,
+    offsets: Vec<usize>,
+}
+
+impl Pattern {
+    /// Validate a matrix and record its sparsity pattern.
+    ///
+    /// The matrix is expected to be square and stored in the compressed-column
+    /// variant.
+    fn new<T: Element>(matrix: &Compressed<T>) -> Result<Self> {
+        square!(matrix);
+        if matrix.variant != Variant::Column {
+            raise!("expected a matrix in the compressed-column variant");
+        }
+        matrix.validate()?;
+        Ok(Pattern {
+            size: matrix.columns,
+            indices: matrix.indices.clone(),
+            offsets: matrix.offsets.clone(),
+        })
+    }
+
+    /// Check that a matrix has the recorded sparsity pattern.
+    fn check<T: Element>(&self, matrix: &Compressed<T>) -> Result<()> {
+        if matrix.rows != self.size
+            || matrix.columns != self.size
+            || matrix.variant != Variant::Column
+            || matrix.offsets != self.offsets
+            || matrix.indices != self.indices
+        {
+            raise!("the sparsity pattern differs from the analyzed one");
+        }
+        Ok(())
+    }
+}
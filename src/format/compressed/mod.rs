@@ -6,12 +6,18 @@
 //! * the [compressed-column][1] variant or
 //! * the [compressed-row][2] variant.
 //!
+//! The sparse decompositions are computed in two phases. The symbolic phase
+//! depends only on the sparsity pattern; it is performed once and then used to
+//! factorize any number of matrices with the same pattern. If a numeric
+//! refactorization fails, the decomposition is left in an unspecified state.
+//!
 //! [1]: http://netlib.org/linalg/html_templates/node92.html
 //! [2]: http://netlib.org/linalg/html_templates/node91.html
 
 use std::{iter, mem, fmt};
 
-use {Element, Matrix, Position, Size};
+use format::Coordinate;
+use {Element, Matrix, Number, Position, Result, Size};
 
 /// A compressed matrix.
 #[derive(Clone, Debug, PartialEq)]
@@ -53,19 +59,12 @@ macro_rules! new(
 );
 
 mod convert;
+mod decomposition;
 mod operation;
+mod ordering;
 
-#[cfg(debug_assertions)]
-impl<T: Element> ::format::Validate for Compressed<T> {
-    fn validate(&self) {
-        assert_eq!(self.nonzeros, self.values.len());
-        assert_eq!(self.nonzeros, self.indices.len());
-        match self.variant {
-            Variant::Column => assert_eq!(self.columns + 1, self.offsets.len()),
-            Variant::Row => assert_eq!(self.rows + 1, self.offsets.len()),
-        }
-    }
-}
+pub use self::decomposition::{NumericCholesky, NumericLU, SymbolicCholesky, SymbolicLU};
+pub use self::ordering::Profile;
 
 /// A variant of a compressed matrix.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -117,6 +116,31 @@ impl<T: Element> Compressed<T> {
         )
     }
 
+    /// Create a matrix from raw parts.
+    ///
+    /// The parts are checked to form a valid matrix, which implies that the
+    /// indices are within the bounds and sorted within each column or row.
+    pub fn from_raw_parts<S: Size>(
+        size: S,
+        variant: Variant,
+        values: Vec<T>,
+        indices: Vec<usize>,
+        offsets: Vec<usize>,
+    ) -> Result<Self> {
+        let (rows, columns) = size.dimensions();
+        let matrix = new!(
+            rows,
+            columns,
+            values.len(),
+            variant,
+            values,
+            indices,
+            offsets
+        );
+        matrix.validate()?;
+        Ok(matrix)
+    }
+
     /// Read an element.
     pub fn get<P: Position>(&self, position: P) -> T {
         let (mut i, mut j) = position.coordinates();
@@ -230,6 +254,85 @@ impl<T: Element> Compressed<T> {
     }
 }
 
+impl<T> Compressed<T>
+where
+    T: Element + Number,
+{
+    /// Create a matrix from an iterator over `(row, column, value)` triplets.
+    ///
+    /// The triplets can come in any order, and the values of triplets sharing
+    /// the same position are summed up.
+    pub fn from_triplets<S, I>(size: S, variant: Variant, triplets: I) -> Result<Self>
+    where
+        S: Size,
+        I: IntoIterator<Item = (usize, usize, T)>,
+    {
+        let mut matrix = Coordinate::new(size);
+        for (i, j, value) in triplets {
+            if i >= matrix.rows || j >= matrix.columns {
+                raise!(
+                    "the position ({}, {}) is out of the bounds of a {} × {} matrix",
+                    i,
+                    j,
+                    matrix.rows,
+                    matrix.columns
+                );
+            }
+            matrix.push((i, j), value);
+        }
+        Ok(matrix.to_compressed(variant))
+    }
+}
+
+impl<T: Element> Compressed<T> {
+    /// Check that the matrix is valid.
+    ///
+    /// Apart from the sizes of the vectors, the offsets should be
+    /// nondecreasing, and the indices should be within the bounds and strictly
+    /// increasing within each column or row.
+    pub fn validate(&self) -> Result<()> {
+        let (majors, minors) = match self.variant {
+            Variant::Column => (self.columns, self.rows),
+            Variant::Row => (self.rows, self.columns),
+        };
+        if self.values.len() != self.nonzeros || self.indices.len() != self.nonzeros {
+            raise!(
+                "expected {} values and indices but got {} and {}",
+                self.nonzeros,
+                self.values.len(),
+                self.indices.len()
+            );
+        }
+        if self.offsets.len() != majors + 1 {
+            raise!(
+                "expected {} offsets but got {}",
+                majors + 1,
+                self.offsets.len()
+            );
+        }
+        if self.offsets[0] != 0 || self.offsets[majors] != self.nonzeros {
+            raise!("expected the offsets to start at zero and end at the number of nonzeros");
+        }
+        for major in 0..majors {
+            let (start, finish) = (self.offsets[major], self.offsets[major + 1]);
+            if start > finish {
+                raise!("expected the offsets to be nondecreasing");
+            }
+            if finish > self.nonzeros {
+                raise!("expected the offsets not to exceed the number of nonzeros");
+            }
+            let indices = &self.indices[start..finish];
+            if indices.iter().any(|&i| i >= minors) {
+                raise!("found an index out of the bounds");
+            }
+            if indices.windows(2).any(|pair| pair[0] >= pair[1]) {
+                raise!("expected the indices to be strictly increasing within each column or row");
+            }
+        }
+        Ok(())
+    }
+}
+
 impl<T: Element> Matrix for Compressed<T> {
     type Element = T;
 
@@ -306,10 +409,45 @@ iterator!(struct Iterator -> (usize, usize, &'l T));
 iterator!(struct IteratorMut -> (usize, usize, &'l mut T));
 
 #[cfg(test)]
-mod tests {
+pub mod tests {
     use format::compressed::Variant;
     use prelude::*;
 
+    /// Create a tridiagonal matrix with constant diagonals.
+    pub fn tridiagonal(size: usize, lower: f64, diagonal: f64, upper: f64) -> Compressed<f64> {
+        let mut triplets = vec![];
+        for i in 0..size {
+            if i > 0 {
+                triplets.push((i, i - 1, lower));
+            }
+            triplets.push((i, i, diagonal));
+            if i + 1 < size {
+                triplets.push((i, i + 1, upper));
+            }
+        }
+        Compressed::from_triplets(size, Variant::Row, triplets).unwrap()
+    }
+
+    /// Create the five-point Laplacian on a square grid.
+    pub fn laplacian(size: usize) -> Compressed<f64> {
+        let mut triplets = vec![];
+        for i in 0..size {
+            for j in 0..size {
+                let k = i * size + j;
+                triplets.push((k, k, 4.0));
+                if i + 1 < size {
+                    triplets.push((k, k + size, -1.0));
+                    triplets.push((k + size, k, -1.0));
+                }
+                if j + 1 < size {
+                    triplets.push((k, k + 1, -1.0));
+                    triplets.push((k + 1, k, -1.0));
+                }
+            }
+        }
+        Compressed::from_triplets(size * size, Variant::Column, triplets).unwrap()
+    }
+
     #[test]
     fn get() {
         let conventional = Conventional::from_vec(
@@ -576,4 +714,97 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn from_raw_parts() {
+        let matrix = Compressed::from_raw_parts(
+            (2, 3),
+            Variant::Row,
+            vec![1.0, 2.0, 3.0],
+            vec![0, 2, 1],
+            vec![0, 2, 3],
+        )
+        .unwrap();
+        assert_eq!(matrix.get((0, 2)), 2.0);
+        assert_eq!(matrix.get((1, 1)), 3.0);
+        assert!(Compressed::from_raw_parts(
+            (2, 3),
+            Variant::Row,
+            vec![1.0, 2.0],
+            vec![2, 0],
+            vec![0, 2, 2],
+        )
+        .is_err());
+        assert!(Compressed::from_raw_parts(
+            (2, 3),
+            Variant::Row,
+            vec![1.0, 2.0],
+            vec![0, 3],
+            vec![0, 2, 2],
+        )
+        .is_err());
+        assert!(Compressed::from_raw_parts(
+            (2, 3),
+            Variant::Column,
+            vec![1.0, 2.0],
+            vec![0, 1],
+            vec![0, 2, 1, 2],
+        )
+        .is_err());
+        assert!(Compressed::from_raw_parts(
+            (2, 3),
+            Variant::Column,
+            vec![1.0, 2.0],
+            vec![0, 1],
+            vec![0, 5, 2, 2],
+        )
+        .is_err());
+        assert!(Compressed::from_raw_parts(
+            (2, 3),
+            Variant::Column,
+            vec![1.0, 2.0],
+            vec![0, 1],
+            vec![0, 1, 1],
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn from_triplets() {
+        let triplets = vec![(1, 0, 1.0), (0, 2, 2.0), (1, 0, 3.0), (0, 0, 4.0)];
+        let matrix = Compressed::from_triplets((2, 3), Variant::Column, triplets).unwrap();
+        assert_eq!(
+            matrix,
+            new!(
+                2,
+                3,
+                3,
+                Variant::Column,
+                vec![4.0, 4.0, 2.0],
+                vec![0, 1, 0],
+                vec![0, 2, 2, 3]
+            )
+        );
+        assert!(Compressed::from_triplets((2, 3), Variant::Row, vec![(2, 0, 1.0)]).is_err());
+    }
+
+    #[test]
+    fn validate() {
+        let mut matrix = new!(
+            2,
+            3,
+            2,
+            Variant::Row,
+            vec![1.0, 2.0],
+            vec![0, 2],
+            vec![0, 2, 2]
+        );
+        assert!(matrix.validate().is_ok());
+        matrix.indices.swap(0, 1);
+        assert!(matrix.validate().is_err());
+        matrix.indices = vec![1, 1];
+        assert!(matrix.validate().is_err());
+        matrix.nonzeros = 3;
+        assert!(matrix.validate().is_err());
+    }
 }
@@ -1,7 +1,64 @@
+use std::borrow::Cow;
+use std::ops::{Add, Sub};
+
+use format::compressed::Variant;
 use format::{Compressed, Conventional, Diagonal};
 use operation::{Multiply, MultiplyInto, MultiplySelf, Transpose};
 use {Element, Number};
 
+macro_rules! implement(
+    ($operator:ident, $function:ident, $verb:expr) => (
+        impl<'l, 'r, T> $operator<&'r Compressed<T>> for &'l Compressed<T>
+        where
+            T: Element + Number,
+        {
+            type Output = Compressed<T>;
+
+            #[inline]
+            fn $function(self, right: &'r Compressed<T>) -> Self::Output {
+                conform!(self, right, $verb);
+                combine(self, right, <T as $operator>::$function)
+            }
+        }
+
+        impl<'l, 'r, T> $operator<&'r Diagonal<T>> for &'l Compressed<T>
+        where
+            T: Element + Number,
+        {
+            type Output = Compressed<T>;
+
+            #[inline]
+            fn $function(self, right: &'r Diagonal<T>) -> Self::Output {
+                conform!(self, right, $verb);
+                let right = diagonal(right, self.variant);
+                combine(self, &right, <T as $operator>::$function)
+            }
+        }
+
+        impl<'l, 'r, T> $operator<&'r Compressed<T>> for &'l Diagonal<T>
+        where
+            T: Element + Number,
+        {
+            type Output = Compressed<T>;
+
+            #[inline]
+            fn $function(self, right: &'r Compressed<T>) -> Self::Output {
+                conform!(self, right, $verb);
+                combine(&diagonal(self, right.variant), right, <T as $operator>::$function)
+            }
+        }
+
+        forward!($operator, $function, Compressed, Compressed, Compressed);
+        forward!($operator, $function, Compressed, Diagonal, Compressed);
+        forward!($operator, $function, Diagonal, Compressed, Compressed);
+    );
+);
+
+implement!(Add, add, "add");
+implement!(Sub, sub, "subtract");
+
+scalar!(Compressed);
+
 impl<T> Multiply<Diagonal<T>, Compressed<T>> for Compressed<T>
 where
     T: Element + Number,
@@ -14,6 +71,18 @@ where
     }
 }
 
+impl<T> Multiply<Compressed<T>, Compressed<T>> for Compressed<T>
+where
+    T: Element + Number,
+{
+    #[inline]
+    fn multiply(&self, right: &Self) -> Self {
+        let mut result = self.multiply_symbolic(right);
+        self.multiply_into_pattern(right, &mut result);
+        result
+    }
+}
+
 impl<'l, T> MultiplyInto<[T], [T]> for Compressed<T>
 where
     T: Element + Number,
@@ -21,7 +90,7 @@ where
     #[inline]
     fn multiply_into(&self, right: &[T], result: &mut [T]) {
         let (m, p) = (self.rows, self.columns);
-        let n = right.len() / p;
+        let n = multiplicand!(m, p, right, result);
         multiply_matrix_left(self, right, result, m, p, n)
     }
 }
@@ -33,6 +102,19 @@ where
     #[inline]
     fn multiply_into(&self, right: &Compressed<T>, result: &mut [T]) {
         let (m, p, n) = (self.rows, self.columns, right.columns);
+        if p != right.rows {
+            panic!(
+                "cannot multiply a {} × {} matrix and a {} × {} matrix",
+                m, p, right.rows, n
+            );
+        }
+        if result.len() != m * n {
+            panic!(
+                "expected a result of {} elements but got {}",
+                m * n,
+                result.len()
+            );
+        }
         multiply_matrix_right(&self.values, right, result, m, p, n)
     }
 }
@@ -43,7 +125,12 @@ where
 {
     fn multiply_self(&mut self, right: &Diagonal<T>) {
         let (m, n) = (self.rows, right.columns);
-        debug_assert_eq!(self.columns, right.rows);
+        if self.columns != right.rows {
+            panic!(
+                "cannot multiply a {} × {} matrix and a {} × {} matrix",
+                m, self.columns, right.rows, n
+            );
+        }
         self.resize((m, n));
         for (_, j, value) in self.iter_mut() {
             *value = *value * right[j];
@@ -51,6 +138,150 @@ where
     }
 }
 
+impl<T: Element> Compressed<T> {
+    /// Compute the sparsity pattern of the product with another matrix.
+    ///
+    /// The result is in the variant of the receiver and has zero values. The
+    /// values of the product can then be computed via `multiply_into_pattern`,
+    /// which can be repeated whenever the values of the operands change but
+    /// their patterns do not.
+    pub fn multiply_symbolic(&self, right: &Self) -> Self {
+        if self.columns != right.rows {
+            panic!(
+                "cannot multiply a {} × {} matrix and a {} × {} matrix",
+                self.rows, self.columns, right.rows, right.columns
+            );
+        }
+        let variant = self.variant;
+        let right = coerce(right, variant);
+        let (outer, inner, minors) = match variant {
+            Variant::Column => (&*right, self, self.rows),
+            Variant::Row => (self, &*right, right.columns),
+        };
+        let majors = outer.offsets.len() - 1;
+        let mut offsets = Vec::with_capacity(majors + 1);
+        let mut indices = vec![];
+        let mut marks = vec![majors; minors];
+        offsets.push(0);
+        for j in 0..majors {
+            let start = indices.len();
+            for k in outer.offsets[j]..outer.offsets[j + 1] {
+                let l = outer.indices[k];
+                for &i in &inner.indices[inner.offsets[l]..inner.offsets[l + 1]] {
+                    if marks[i] != j {
+                        marks[i] = j;
+                        indices.push(i);
+                    }
+                }
+            }
+            indices[start..].sort_unstable();
+            offsets.push(indices.len());
+        }
+        let nonzeros = indices.len();
+        new!(
+            self.rows,
+            right.columns,
+            nonzeros,
+            variant,
+            vec![T::zero(); nonzeros],
+            indices,
+            offsets
+        )
+    }
+}
+
+impl<T> Compressed<T>
+where
+    T: Element + Number,
+{
+    /// Multiply by another matrix and add the product to a third one with a
+    /// fixed sparsity pattern.
+    ///
+    /// The pattern of the result is assumed to have been obtained via
+    /// `multiply_symbolic` and is left intact. The function panics if the
+    /// pattern does not cover the one of the product.
+    pub fn multiply_into_pattern(&self, right: &Self, result: &mut Self) {
+        if self.columns != right.rows || (self.rows, right.columns) != (result.rows, result.columns)
+        {
+            panic!(
+                "cannot multiply a {} × {} matrix and a {} × {} matrix into a {} × {} matrix",
+                self.rows, self.columns, right.rows, right.columns, result.rows, result.columns
+            );
+        }
+        let variant = result.variant;
+        let (left, right) = (coerce(self, variant), coerce(right, variant));
+        let (outer, inner, minors) = match variant {
+            Variant::Column => (&*right, &*left, left.rows),
+            Variant::Row => (&*left, &*right, right.columns),
+        };
+        let mut work = vec![<T as Element>::zero(); minors];
+        let mut covered = vec![false; minors];
+        for j in 0..(outer.offsets.len() - 1) {
+            let (start, finish) = (result.offsets[j], result.offsets[j + 1]);
+            for &i in &result.indices[start..finish] {
+                covered[i] = true;
+            }
+            for k in outer.offsets[j]..outer.offsets[j + 1] {
+                let (l, value) = (outer.indices[k], outer.values[k]);
+                for p in inner.offsets[l]..inner.offsets[l + 1] {
+                    let i = inner.indices[p];
+                    if !covered[i] {
+                        panic!("the pattern of the result does not cover the product");
+                    }
+                    work[i] = work[i] + inner.values[p] * value;
+                }
+            }
+            for p in start..finish {
+                let i = result.indices[p];
+                result.values[p] = result.values[p] + work[i];
+                work[i] = <T as Element>::zero();
+                covered[i] = false;
+            }
+        }
+    }
+
+    /// Compute `alpha * self + beta * right`.
+    ///
+    /// The result is in the variant of the receiver.
+    pub fn scale_add(&self, alpha: T, right: &Self, beta: T) -> Self {
+        conform!(self, right, "add");
+        combine(self, right, |x, y| alpha * x + beta * y)
+    }
+
+    /// Compute the element-wise (Hadamard) product.
+    ///
+    /// The result is in the variant of the receiver, and its sparsity pattern
+    /// is the intersection of the patterns of the two operands.
+    pub fn hadamard(&self, right: &Self) -> Self {
+        conform!(self, right, "multiply element-wise");
+        let right = coerce(right, self.variant);
+        let mut result = Compressed::with_capacity(
+            (self.rows, self.columns),
+            self.variant,
+            min!(self.nonzeros, right.nonzeros),
+        );
+        for major in 0..(self.offsets.len() - 1) {
+            let (mut k, finish) = (self.offsets[major], self.offsets[major + 1]);
+            let (mut l, other) = (right.offsets[major], right.offsets[major + 1]);
+            while k < finish && l < other {
+                if self.indices[k] < right.indices[l] {
+                    k += 1;
+                } else if right.indices[l] < self.indices[k] {
+                    l += 1;
+                } else {
+                    result.indices.push(self.indices[k]);
+                    result.values.push(self.values[k] * right.values[l]);
+                    k += 1;
+                    l += 1;
+                }
+            }
+            result.offsets[major + 1] = result.values.len();
+        }
+        result.nonzeros = result.values.len();
+        result
+    }
+}
+
 impl<T: Element> Transpose for Compressed<T> {
     fn transpose(&self) -> Self {
         let &Compressed {
@@ -58,16 +289,86 @@ impl<T: Element> Transpose for Compressed<T> {
             columns,
             nonzeros,
             variant,
-            ..
+            ref values,
+            ref indices,
+            ref offsets,
         } = self;
-        let mut matrix = Compressed::with_capacity((columns, rows), variant, nonzeros);
-        for (i, j, &value) in self.iter() {
-            matrix.set((j, i), value);
+        let matrix = new!(
+            columns,
+            rows,
+            nonzeros,
+            variant.flip(),
+            values.clone(),
+            indices.clone(),
+            offsets.clone()
+        );
+        matrix.to_variant(variant)
+    }
+}
+
+/// Combine two matrices of the same size element by element.
+///
+/// The result is in the variant of the left operand, and its sparsity pattern
+/// is the union of the patterns of the two operands. An element present in
+/// only one of the operands is combined with zero.
+fn combine<T, F>(left: &Compressed<T>, right: &Compressed<T>, operation: F) -> Compressed<T>
+where
+    T: Element,
+    F: Fn(T, T) -> T,
+{
+    let right = coerce(right, left.variant);
+    let mut result = Compressed::with_capacity(
+        (left.rows, left.columns),
+        left.variant,
+        left.nonzeros + right.nonzeros,
+    );
+    for major in 0..(left.offsets.len() - 1) {
+        let (mut k, finish) = (left.offsets[major], left.offsets[major + 1]);
+        let (mut l, other) = (right.offsets[major], right.offsets[major + 1]);
+        while k < finish || l < other {
+            if l == other || (k < finish && left.indices[k] < right.indices[l]) {
+                result.indices.push(left.indices[k]);
+                result.values.push(operation(left.values[k], T::zero()));
+                k += 1;
+            } else if k == finish || right.indices[l] < left.indices[k] {
+                result.indices.push(right.indices[l]);
+                result.values.push(operation(T::zero(), right.values[l]));
+                l += 1;
+            } else {
+                result.indices.push(left.indices[k]);
+                result.values.push(operation(left.values[k], right.values[l]));
+                k += 1;
+                l += 1;
+            }
         }
-        matrix
+        result.offsets[major + 1] = result.values.len();
+    }
+    result.nonzeros = result.values.len();
+    result
+}
+
+/// Borrow a matrix if it is in a given variant or convert it otherwise.
+fn coerce<T: Element>(matrix: &Compressed<T>, variant: Variant) -> Cow<'_, Compressed<T>> {
+    if matrix.variant == variant {
+        Cow::Borrowed(matrix)
+    } else {
+        Cow::Owned(matrix.to_variant(variant))
     }
 }
 
+/// Convert a diagonal matrix to a given variant of the compressed format.
+fn diagonal<T: Element>(matrix: &Diagonal<T>, variant: Variant) -> Compressed<T> {
+    let mut result = Compressed::from(matrix);
+    if let Variant::Row = variant {
+        let nonzeros = result.nonzeros;
+        result.variant = variant;
+        result.offsets = (0..(result.rows + 1))
+            .map(|i| if i < nonzeros { i } else { nonzeros })
+            .collect();
+    }
+    result
+}
+
 fn multiply_matrix_left<T>(a: &Compressed<T>, b: &[T], c: &mut [T], m: usize, p: usize, n: usize)
 where
     T: Element + Number,
@@ -81,14 +382,31 @@ where
         ref offsets,
         ..
     } = a;
-    for j in 0..n {
-        let bo = j * p;
-        let co = j * m;
-        for l in 0..p {
-            let bi = bo + l;
-            for k in offsets[l]..offsets[l + 1] {
-                let i = co + indices[k];
-                c[i] = c[i] + values[k] * b[bi];
+    match a.variant {
+        Variant::Column => {
+            for j in 0..n {
+                let bo = j * p;
+                let co = j * m;
+                for l in 0..p {
+                    let bi = bo + l;
+                    for k in offsets[l]..offsets[l + 1] {
+                        let i = co + indices[k];
+                        c[i] = c[i] + values[k] * b[bi];
+                    }
+                }
+            }
+        }
+        Variant::Row => {
+            for j in 0..n {
+                let bo = j * p;
+                let co = j * m;
+                for i in 0..m {
+                    let mut sum = c[co + i];
+                    for k in offsets[i]..offsets[i + 1] {
+                        sum = sum + values[k] * b[bo + indices[k]];
+                    }
+                    c[co + i] = sum;
+                }
             }
         }
     }
@@ -107,12 +425,27 @@ where
         ref offsets,
         ..
     } = b;
-    for j in 0..n {
-        let co = j * m;
-        for k in offsets[j]..offsets[j + 1] {
-            let ao = indices[k] * m;
-            for i in 0..m {
-                c[co + i] = c[co + i] + values[k] * a[ao + i];
+    match b.variant {
+        Variant::Column => {
+            for j in 0..n {
+                let co = j * m;
+                for k in offsets[j]..offsets[j + 1] {
+                    let ao = indices[k] * m;
+                    for i in 0..m {
+                        c[co + i] = c[co + i] + values[k] * a[ao + i];
+                    }
+                }
+            }
+        }
+        Variant::Row => {
+            for l in 0..p {
+                let ao = l * m;
+                for k in offsets[l]..offsets[l + 1] {
+                    let co = indices[k] * m;
+                    for i in 0..m {
+                        c[co + i] = c[co + i] + values[k] * a[ao + i];
+                    }
+                }
             }
         }
     }
@@ -123,6 +456,206 @@ mod tests {
     use format::compressed::Variant;
     use prelude::*;
 
+    fn variant(matrix: &Conventional<f64>, variant: Variant) -> Compressed<f64> {
+        Compressed::from(matrix).into_variant(variant)
+    }
+
+    #[test]
+    fn add() {
+        let left = new!(
+            3,
+            2,
+            3,
+            Variant::Column,
+            vec![1.0, 2.0, 3.0],
+            vec![1, 0, 2],
+            vec![0, 1, 3]
+        );
+        let right = new!(
+            3,
+            2,
+            3,
+            Variant::Row,
+            vec![4.0, 5.0, 6.0],
+            vec![1, 0, 1],
+            vec![0, 1, 2, 3]
+        );
+        let result = &left + &right;
+        assert_eq!(
+            result,
+            new!(
+                3,
+                2,
+                3,
+                Variant::Column,
+                vec![6.0, 6.0, 9.0],
+                vec![1, 0, 2],
+                vec![0, 1, 3]
+            )
+        );
+        let result = &right - &left;
+        assert_eq!(result.variant, Variant::Row);
+        assert_eq!(&result.values, &[2.0, 4.0, 3.0]);
+        assert_eq!(&result.indices, &[1, 0, 1]);
+        assert_eq!(&result.offsets, &[0, 1, 2, 3]);
+        let result = left.scale_add(2.0, &right, -1.0);
+        assert_eq!(&result.values, &[-3.0, 0.0, 0.0]);
+        assert_eq!(&result.indices, &[1, 0, 2]);
+        assert_eq!(&result.offsets, &[0, 1, 3]);
+    }
+
+    #[test]
+    fn add_diagonal() {
+        let matrix = new!(
+            2,
+            3,
+            2,
+            Variant::Row,
+            vec![1.0, 2.0],
+            vec![2, 1],
+            vec![0, 1, 2]
+        );
+        let diagonal = Diagonal::from_vec((2, 3), vec![3.0, 4.0]);
+        let result = &diagonal - &matrix;
+        assert_eq!(result.variant, Variant::Row);
+        assert_eq!(
+            Conventional::from(&result),
+            Conventional::from(&diagonal) - Conventional::from(&matrix)
+        );
+        let result = matrix + diagonal;
+        assert_eq!(&result.values, &[3.0, 1.0, 6.0]);
+        assert_eq!(&result.offsets, &[0, 2, 3]);
+    }
+
+    #[test]
+    fn hadamard() {
+        let left = new!(
+            3,
+            2,
+            3,
+            Variant::Column,
+            vec![1.0, 2.0, 3.0],
+            vec![1, 0, 2],
+            vec![0, 1, 3]
+        );
+        let right = new!(
+            3,
+            2,
+            3,
+            Variant::Row,
+            vec![4.0, 5.0, 6.0],
+            vec![1, 1, 1],
+            vec![0, 1, 2, 3]
+        );
+        let result = left.hadamard(&right);
+        assert_eq!(
+            result,
+            new!(
+                3,
+                2,
+                2,
+                Variant::Column,
+                vec![8.0, 18.0],
+                vec![0, 2],
+                vec![0, 0, 2]
+            )
+        );
+        let result = right.hadamard(&left);
+        assert_eq!(result.variant, Variant::Row);
+        assert_eq!(&result.values, &[8.0, 18.0]);
+        assert_eq!(&result.indices, &[1, 1]);
+        assert_eq!(&result.offsets, &[0, 1, 1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot multiply element-wise a 2 × 2 matrix and a 2 × 3 matrix")]
+    fn hadamard_mismatch() {
+        let left = Compressed::<f64>::new(2, Variant::Column);
+        let right = Compressed::new((2, 3), Variant::Row);
+        let _ = left.hadamard(&right);
+    }
+
+    #[test]
+    fn scale() {
+        let matrix = new!(2, 2, 1, Variant::Column, vec![2.0], vec![1], vec![0, 1, 1]);
+        assert_eq!(&(-matrix * 0.5).values, &[-1.0]);
+    }
+
+    #[test]
+    fn multiply() {
+        let left = Conventional::from_vec(
+            (3, 4),
+            matrix![
+                1.0, 0.0, 2.0, 0.0;
+                0.0, 3.0, 0.0, 0.0;
+                4.0, 0.0, 0.0, 5.0;
+            ],
+        );
+        let right = Conventional::from_vec(
+            (4, 2),
+            matrix![
+                0.0, 6.0;
+                7.0, 0.0;
+                8.0, 0.0;
+                0.0, 9.0;
+            ],
+        );
+        let expected = left.multiply(&right);
+        for &first in &[Variant::Column, Variant::Row] {
+            for &second in &[Variant::Column, Variant::Row] {
+                let result = variant(&left, first).multiply(&variant(&right, second));
+                assert_eq!(result.variant, first);
+                assert_eq!(Conventional::from(&result), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn multiply_symbolic() {
+        let mut left = new!(
+            2,
+            2,
+            3,
+            Variant::Row,
+            vec![1.0, 2.0, 3.0],
+            vec![0, 1, 1],
+            vec![0, 2, 3]
+        );
+        let right = new!(
+            2,
+            2,
+            2,
+            Variant::Column,
+            vec![4.0, 5.0],
+            vec![0, 1],
+            vec![0, 1, 2]
+        );
+        let mut result = left.multiply_symbolic(&right);
+        assert_eq!(result.variant, Variant::Row);
+        assert_eq!(&result.indices, &[0, 1, 1]);
+        assert_eq!(&result.offsets, &[0, 2, 3]);
+        assert_eq!(&result.values, &[0.0, 0.0, 0.0]);
+        left.multiply_into_pattern(&right, &mut result);
+        assert_eq!(&result.values, &[4.0, 10.0, 15.0]);
+        for value in &mut left.values {
+            *value *= 2.0;
+        }
+        for value in &mut result.values {
+            *value = 0.0;
+        }
+        left.multiply_into_pattern(&right, &mut result);
+        assert_eq!(&result.values, &[8.0, 20.0, 30.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "the pattern of the result does not cover the product")]
+    fn multiply_uncovered() {
+        let left = Compressed::from(Diagonal::from_vec(2, vec![1.0, 2.0]));
+        let right = Compressed::from(Conventional::from_vec(2, vec![1.0, 1.0, 1.0, 1.0]));
+        let mut result = left.multiply_symbolic(&left);
+        left.multiply_into_pattern(&right, &mut result);
+    }
+
     #[test]
     fn multiply_self() {
         let mut matrix = new!(
@@ -148,11 +681,33 @@ mod tests {
                 vec![0, 1, 3, 3, 3]
             )
         );
+        let mut matrix = new!(
+            3,
+            2,
+            3,
+            Variant::Row,
+            vec![1.0, 2.0, 3.0],
+            vec![1, 0, 1],
+            vec![0, 1, 2, 3]
+        );
+        matrix.multiply_self(&right);
+        assert_eq!(
+            matrix,
+            new!(
+                3,
+                4,
+                3,
+                Variant::Row,
+                vec![5.0, 8.0, 15.0],
+                vec![1, 0, 1],
+                vec![0, 1, 2, 3]
+            )
+        );
     }
 
     #[test]
     fn multiply_into_left() {
-        let matrix = Compressed::from(Conventional::from_vec(
+        let matrix = Conventional::from_vec(
             (4, 3),
             matrix![
                 1.0, 5.0, 4.0;
@@ -160,7 +715,7 @@ mod tests {
                 3.0, 6.0, 2.0;
                 4.0, 5.0, 1.0;
             ],
-        ));
+        );
         let right = Conventional::from_vec(
             (3, 2),
             matrix![
@@ -169,25 +724,19 @@ mod tests {
                 3.0, 6.0;
             ],
         );
-        let mut result = Conventional::from_vec(
-            (4, 2),
-            matrix![
-                1.0, 1.0;
-                1.0, 1.0;
-                1.0, 1.0;
-                1.0, 1.0;
-            ],
-        );
-        matrix.multiply_into(&right, &mut result);
-        assert_eq!(
-            &result.values,
-            &matrix![
-                24.0, 54.0;
-                24.0, 57.0;
-                22.0, 55.0;
-                18.0, 48.0;
-            ]
-        );
+        for &kind in &[Variant::Column, Variant::Row] {
+            let mut result = Conventional::from_vec((4, 2), vec![1.0; 8]);
+            variant(&matrix, kind).multiply_into(&right, &mut result);
+            assert_eq!(
+                &result.values,
+                &matrix![
+                    24.0, 54.0;
+                    24.0, 57.0;
+                    22.0, 55.0;
+                    18.0, 48.0;
+                ]
+            );
+        }
     }
 
     #[test]
@@ -201,33 +750,27 @@ mod tests {
                 4.0, 5.0, 1.0;
             ],
         );
-        let right = Compressed::from(Conventional::from_vec(
+        let right = Conventional::from_vec(
             (3, 2),
             matrix![
                 1.0, 4.0;
-                2.0, 5.0;
-                3.0, 6.0;
-            ],
-        ));
-        let mut result = Conventional::from_vec(
-            (4, 2),
-            matrix![
-                1.0, 1.0;
-                1.0, 1.0;
-                1.0, 1.0;
-                1.0, 1.0;
+                0.0, 5.0;
+                3.0, 0.0;
             ],
         );
-        matrix.multiply_into(&right, &mut result);
-        assert_eq!(
-            &result.values,
-            &matrix![
-                24.0, 54.0;
-                24.0, 57.0;
-                22.0, 55.0;
-                18.0, 48.0;
-            ]
-        );
+        for &kind in &[Variant::Column, Variant::Row] {
+            let mut result = Conventional::from_vec((4, 2), vec![1.0; 8]);
+            matrix.multiply_into(&variant(&right, kind), &mut result);
+            assert_eq!(
+                &result.values,
+                &matrix![
+                    14.0, 30.0;
+                    12.0, 39.0;
+                    10.0, 43.0;
+                     8.0, 42.0;
+                ]
+            );
+        }
     }
 
     #[test]
@@ -254,5 +797,26 @@ mod tests {
                 vec![0, 1, 3, 3, 4, 5]
             )
         );
+        let matrix = new!(
+            2,
+            3,
+            3,
+            Variant::Row,
+            vec![1.0, 2.0, 3.0],
+            vec![2, 0, 2],
+            vec![0, 1, 3]
+        );
+        assert_eq!(
+            matrix.transpose(),
+            new!(
+                3,
+                2,
+                3,
+                Variant::Row,
+                vec![2.0, 1.0, 3.0],
+                vec![1, 0, 1],
+                vec![0, 1, 1, 3]
+            )
+        );
     }
 }
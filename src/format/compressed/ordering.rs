@@ -0,0 +1,435 @@
+use std::collections::BTreeSet;
+
+use format::Compressed;
+use {Element, Result};
+
+/// The bandwidth and profile of a matrix.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Profile {
+    /// The number of superdiagonals containing nonzero elements.
+    pub superdiagonals: usize,
+    /// The number of subdiagonals containing nonzero elements.
+    pub subdiagonals: usize,
+    /// The number of elements between the first nonzero element of each row
+    /// and the diagonal in the lower triangle plus the number of elements
+    /// between the first nonzero element of each column and the diagonal in
+    /// the upper triangle.
+    pub envelope: usize,
+}
+
+impl<T: Element> Compressed<T> {
+    /// Compute a fill-reducing ordering by the approximate minimum-degree
+    /// algorithm.
+    ///
+    /// The ordering is computed for the pattern of `A + Aᵀ` and is intended
+    /// for `permute_symmetric`. The elimination is performed on a quotient
+    /// graph, and the degrees of variables are replaced with the upper bounds
+    /// of Amestoy, Davis, and Duff, which are cheaper to update.
+    pub fn minimum_degree(&self) -> Result<Vec<usize>> {
+        let n = square!(self);
+        let mut variables = self.adjacency();
+        let mut elements = vec![Vec::<usize>::new(); n];
+        let mut members = vec![Vec::<usize>::new(); n];
+        let mut degrees = variables.iter().map(Vec::len).collect::<Vec<_>>();
+        let mut queue = (0..n).map(|i| (degrees[i], i)).collect::<BTreeSet<_>>();
+        let mut eliminated = vec![false; n];
+        let mut absorbed = vec![false; n];
+        let mut marks = vec![n; n];
+        let mut stamps = vec![n; n];
+        let mut external = vec![0; n];
+        let mut permutation = Vec::with_capacity(n);
+        for k in 0..n {
+            let p = match queue.iter().next() {
+                Some(&(_, p)) => p,
+                None => break,
+            };
+            queue.remove(&(degrees[p], p));
+            eliminated[p] = true;
+            permutation.push(p);
+            marks[p] = k;
+            let mut pattern = vec![];
+            for &i in &variables[p] {
+                if !eliminated[i] && marks[i] != k {
+                    marks[i] = k;
+                    pattern.push(i);
+                }
+            }
+            for &e in &elements[p] {
+                if absorbed[e] {
+                    continue;
+                }
+                for &i in &members[e] {
+                    if !eliminated[i] && marks[i] != k {
+                        marks[i] = k;
+                        pattern.push(i);
+                    }
+                }
+                absorbed[e] = true;
+            }
+            for e in elements[p].drain(..) {
+                members[e] = vec![];
+            }
+            variables[p] = vec![];
+            for &i in &pattern {
+                for &e in &elements[i] {
+                    if absorbed[e] {
+                        continue;
+                    }
+                    if stamps[e] != k {
+                        stamps[e] = k;
+                        members[e].retain(|&j| !eliminated[j]);
+                        external[e] = members[e].len();
+                    }
+                    external[e] -= 1;
+                }
+            }
+            for &i in &pattern {
+                for &e in &elements[i] {
+                    if stamps[e] == k && external[e] == 0 {
+                        absorbed[e] = true;
+                    }
+                }
+            }
+            for &i in &pattern {
+                queue.remove(&(degrees[i], i));
+                elements[i].retain(|&e| !absorbed[e]);
+                variables[i].retain(|&j| !eliminated[j] && marks[j] != k);
+                let mut degree = variables[i].len() + pattern.len() - 1;
+                for &e in &elements[i] {
+                    degree += external[e];
+                }
+                degrees[i] = min!(n - k - 2, min!(degrees[i] + pattern.len() - 1, degree));
+                elements[i].push(p);
+                queue.insert((degrees[i], i));
+            }
+            members[p] = pattern;
+        }
+        Ok(permutation)
+    }
+
+    /// Compute a bandwidth-reducing ordering by the reverse Cuthill–McKee
+    /// algorithm.
+    ///
+    /// The ordering is computed for the pattern of `A + Aᵀ` and is intended
+    /// for `permute_symmetric`. Each connected component is traversed in the
+    /// breadth-first order starting from a pseudo-peripheral vertex found by
+    /// the algorithm of George and Liu.
+    pub fn cuthill_mckee(&self) -> Result<Vec<usize>> {
+        let n = square!(self);
+        let adjacency = self.adjacency();
+        let mut levels = vec![n; n];
+        let mut visited = vec![false; n];
+        let mut order = Vec::with_capacity(n);
+        for start in 0..n {
+            if visited[start] {
+                continue;
+            }
+            let root = peripheral(&adjacency, start, &mut levels);
+            let mut head = order.len();
+            visited[root] = true;
+            order.push(root);
+            while head < order.len() {
+                let i = order[head];
+                head += 1;
+                let mut next = adjacency[i]
+                    .iter()
+                    .cloned()
+                    .filter(|&j| !visited[j])
+                    .collect::<Vec<_>>();
+                next.sort_by_key(|&j| (adjacency[j].len(), j));
+                for j in next {
+                    visited[j] = true;
+                    order.push(j);
+                }
+            }
+        }
+        order.reverse();
+        Ok(order)
+    }
+
+    /// Permute the rows and columns symmetrically.
+    ///
+    /// The function returns `P × A × Pᵀ` whose `(i, j)`th element is the
+    /// `(permutation[i], permutation[j])`th element of the original matrix.
+    pub fn permute_symmetric(&self, permutation: &[usize]) -> Result<Self> {
+        let n = square!(self);
+        if permutation.len() != n {
+            raise!(DimensionMismatch {
+                left: (n, n),
+                right: (permutation.len(), 1),
+            });
+        }
+        let mut inverse = vec![n; n];
+        for (k, &i) in permutation.iter().enumerate() {
+            if i >= n || inverse[i] != n {
+                raise!("expected a permutation of {} elements", n);
+            }
+            inverse[i] = k;
+        }
+        let mut values = Vec::with_capacity(self.nonzeros);
+        let mut indices = Vec::with_capacity(self.nonzeros);
+        let mut offsets = Vec::with_capacity(n + 1);
+        offsets.push(0);
+        let mut pairs = vec![];
+        for &major in permutation {
+            pairs.clear();
+            for k in self.offsets[major]..self.offsets[major + 1] {
+                pairs.push((inverse[self.indices[k]], self.values[k]));
+            }
+            pairs.sort_unstable_by_key(|pair| pair.0);
+            for &(index, value) in &pairs {
+                indices.push(index);
+                values.push(value);
+            }
+            offsets.push(indices.len());
+        }
+        Ok(new!(
+            n,
+            n,
+            self.nonzeros,
+            self.variant,
+            values,
+            indices,
+            offsets
+        ))
+    }
+
+    /// Compute the bandwidth and profile.
+    ///
+    /// The result indicates whether the matrix is worth converting into the
+    /// banded format, which stores `superdiagonals + 1 + subdiagonals`
+    /// elements per column.
+    pub fn profile(&self) -> Profile {
+        let (mut superdiagonals, mut subdiagonals) = (0, 0);
+        let mut first_columns = (0..self.rows).collect::<Vec<_>>();
+        let mut first_rows = (0..self.columns).collect::<Vec<_>>();
+        for (i, j, _) in self.iter() {
+            if i > j {
+                subdiagonals = subdiagonals.max(i - j);
+                first_columns[i] = min!(first_columns[i], j);
+            } else if j > i {
+                superdiagonals = superdiagonals.max(j - i);
+                first_rows[j] = min!(first_rows[j], i);
+            }
+        }
+        let envelope = first_columns
+            .iter()
+            .enumerate()
+            .chain(first_rows.iter().enumerate())
+            .map(|(i, &first)| i - first)
+            .sum();
+        Profile {
+            superdiagonals,
+            subdiagonals,
+            envelope,
+        }
+    }
+
+    /// Compute the adjacency lists of the graph of `A + Aᵀ`.
+    fn adjacency(&self) -> Vec<Vec<usize>> {
+        let mut adjacency = vec![vec![]; self.rows];
+        for (i, j, _) in self.iter() {
+            if i != j {
+                adjacency[i].push(j);
+                adjacency[j].push(i);
+            }
+        }
+        for neighbors in &mut adjacency {
+            neighbors.sort_unstable();
+            neighbors.dedup();
+        }
+        adjacency
+    }
+}
+
+/// Find a pseudo-peripheral vertex in the component of a vertex.
+fn peripheral(adjacency: &[Vec<usize>], start: usize, levels: &mut [usize]) -> usize {
+    let (mut root, mut last, mut depth) = (start, vec![], 0);
+    structure(adjacency, root, levels, &mut last, &mut depth);
+    loop {
+        let candidate = *last
+            .iter()
+            .min_by_key(|&&i| (adjacency[i].len(), i))
+            .unwrap();
+        let (mut next, mut height) = (vec![], 0);
+        structure(adjacency, candidate, levels, &mut next, &mut height);
+        if height <= depth {
+            return root;
+        }
+        root = candidate;
+        last = next;
+        depth = height;
+    }
+}
+
+/// Compute the level structure rooted at a vertex.
+///
+/// The vertices of the last level are written to `last` and the number of
+/// levels less one to `depth`. The array `levels` is expected to be filled
+/// with values greater than the number of vertices and is left so.
+fn structure(
+    adjacency: &[Vec<usize>],
+    root: usize,
+    levels: &mut [usize],
+    last: &mut Vec<usize>,
+    depth: &mut usize,
+) {
+    let unvisited = levels[root];
+    let mut queue = vec![root];
+    levels[root] = 0;
+    let mut head = 0;
+    while head < queue.len() {
+        let i = queue[head];
+        head += 1;
+        for &j in &adjacency[i] {
+            if levels[j] == unvisited {
+                levels[j] = levels[i] + 1;
+                queue.push(j);
+            }
+        }
+    }
+    *depth = levels[queue[queue.len() - 1]];
+    last.clear();
+    last.extend(queue.iter().cloned().filter(|&i| levels[i] == *depth));
+    for &i in &queue {
+        levels[i] = unvisited;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use format::compressed::tests::laplacian;
+    use format::compressed::{Profile, SymbolicCholesky, Variant};
+    use prelude::*;
+    use Error;
+
+    fn fill(matrix: &Compressed<f64>) -> usize {
+        SymbolicCholesky::new(matrix).unwrap().counts.iter().sum()
+    }
+
+    fn check(permutation: &[usize], size: usize) {
+        let mut sorted = permutation.to_vec();
+        sorted.sort();
+        assert_eq!(sorted, (0..size).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn cuthill_mckee() {
+        let scramble = [3, 7, 0, 5, 1, 6, 2, 4];
+        let mut triplets = vec![];
+        for k in 0..8 {
+            triplets.push((scramble[k], scramble[k], 2.0));
+            if k > 0 {
+                triplets.push((scramble[k], scramble[k - 1], -1.0));
+                triplets.push((scramble[k - 1], scramble[k], -1.0));
+            }
+        }
+        let matrix = Compressed::from_triplets(8, Variant::Column, triplets).unwrap();
+        assert!(matrix.profile().subdiagonals > 1);
+        let permutation = matrix.cuthill_mckee().unwrap();
+        check(&permutation, 8);
+        let matrix = matrix.permute_symmetric(&permutation).unwrap();
+        assert_eq!(
+            matrix.profile(),
+            Profile {
+                superdiagonals: 1,
+                subdiagonals: 1,
+                envelope: 14,
+            }
+        );
+
+        let matrix = Compressed::from(Diagonal::from_vec(3, vec![1.0, 2.0, 3.0]));
+        check(&matrix.cuthill_mckee().unwrap(), 3);
+    }
+
+    #[test]
+    fn minimum_degree() {
+        let mut triplets = vec![(0, 0, 10.0)];
+        for i in 1..6 {
+            triplets.push((i, i, 2.0));
+            triplets.push((0, i, 1.0));
+            triplets.push((i, 0, 1.0));
+        }
+        let matrix = Compressed::from_triplets(6, Variant::Column, triplets).unwrap();
+        assert_eq!(fill(&matrix), 15);
+        let permutation = matrix.minimum_degree().unwrap();
+        check(&permutation, 6);
+        assert!(!permutation[..4].contains(&0));
+        assert_eq!(fill(&matrix.permute_symmetric(&permutation).unwrap()), 5);
+
+        let matrix = laplacian(20);
+        let permutation = matrix.minimum_degree().unwrap();
+        check(&permutation, 400);
+        assert!(fill(&matrix.permute_symmetric(&permutation).unwrap()) < fill(&matrix));
+    }
+
+    #[test]
+    fn permute_symmetric() {
+        let dense = Conventional::from_vec(
+            3,
+            matrix![
+                1.0, 2.0, 0.0;
+                0.0, 3.0, 4.0;
+                5.0, 0.0, 6.0;
+            ],
+        );
+        let expected = Conventional::from_vec(
+            3,
+            matrix![
+                6.0, 5.0, 0.0;
+                0.0, 1.0, 2.0;
+                4.0, 0.0, 3.0;
+            ],
+        );
+        for &variant in &[Variant::Column, Variant::Row] {
+            let matrix = Compressed::from(&dense).to_variant(variant);
+            let matrix = matrix.permute_symmetric(&[2, 0, 1]).unwrap();
+            assert!(matrix.validate().is_ok());
+            assert_eq!(matrix.variant, variant);
+            assert_eq!(Conventional::from(matrix), expected);
+        }
+    }
+
+    #[test]
+    fn permute_symmetric_invalid() {
+        let matrix = Compressed::from(Diagonal::from_vec(3, vec![1.0, 2.0, 3.0]));
+        assert!(matrix.permute_symmetric(&[0, 0, 1]).is_err());
+        assert!(matrix.permute_symmetric(&[0, 3, 1]).is_err());
+        assert_eq!(
+            matrix.permute_symmetric(&[0, 1]),
+            Err(Error::DimensionMismatch {
+                left: (3, 3),
+                right: (2, 1),
+            })
+        );
+        let matrix = Compressed::<f64>::new((2, 3), Variant::Column);
+        assert_eq!(
+            matrix.permute_symmetric(&[0, 1]),
+            Err(Error::NotSquare {
+                rows: 2,
+                columns: 3
+            })
+        );
+    }
+
+    #[test]
+    fn profile() {
+        let matrix = Compressed::from(Conventional::from_vec(
+            4,
+            matrix![
+                1.0, 0.0, 2.0, 0.0;
+                0.0, 1.0, 0.0, 0.0;
+                0.0, 3.0, 1.0, 0.0;
+                4.0, 0.0, 0.0, 1.0;
+            ],
+        ));
+        assert_eq!(
+            matrix.profile(),
+            Profile {
+                superdiagonals: 2,
+                subdiagonals: 3,
+                envelope: 2 + 1 + 3,
+            }
+        );
+    }
+}
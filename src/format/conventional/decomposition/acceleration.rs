@@ -1,19 +1,87 @@
 use lapack as backend;
 
-use decomposition::{SingularValue, SymmetricEigen};
+use decomposition::{GeneralEigen, Mode, SingularValue, SymmetricEigen, LU, QR};
 use format::{Conventional, Diagonal};
-use Result;
+use {c64, Result};
+
+use super::{general_eigen, lu, qr};
 
 macro_rules! success(
     ($info:expr) => (
         if $info < 0 {
-            raise!("encountered invalid arguments");
+            raise!(InvalidArgument { info: $info });
         } else if $info > 0 {
-            raise!("failed to converge");
+            raise!(NonConvergence { iterations: None });
         }
     );
 );
 
+impl GeneralEigen<c64> for Conventional<f64> {
+    fn decompose(&self) -> Result<(Diagonal<c64>, Conventional<c64>)> {
+        let m = square!(self);
+        let mut matrix = self.values.clone();
+        let (mut real, mut imaginary) = (vec![0.0; m], vec![0.0; m]);
+        let mut right = vec![0.0; m * m];
+        general_eigen(&mut matrix, &mut real, &mut imaginary, None, &mut right, m)?;
+        Ok(general_eigen::compose(&real, &imaginary, &right, m))
+    }
+
+    fn decompose_with_left(
+        &self,
+    ) -> Result<(Diagonal<c64>, Conventional<c64>, Conventional<c64>)> {
+        let m = square!(self);
+        let mut matrix = self.values.clone();
+        let (mut real, mut imaginary) = (vec![0.0; m], vec![0.0; m]);
+        let (mut left, mut right) = (vec![0.0; m * m], vec![0.0; m * m]);
+        general_eigen(
+            &mut matrix,
+            &mut real,
+            &mut imaginary,
+            Some(&mut left),
+            &mut right,
+            m,
+        )?;
+        let (values, right) = general_eigen::compose(&real, &imaginary, &right, m);
+        let (_, left) = general_eigen::compose(&real, &imaginary, &left, m);
+        Ok((values, right, left))
+    }
+}
+
+impl LU<f64> for Conventional<f64> {
+    fn decompose(&self) -> Result<(Conventional<f64>, Conventional<f64>, Vec<usize>)> {
+        let (m, n) = (self.rows, self.columns);
+        let mut matrix = self.values.clone();
+        let mut pivots = vec![0; min!(m, n)];
+        lower_upper(&mut matrix, &mut pivots, m, n)?;
+        let pivots = pivots.iter().map(|&i| i as usize - 1).collect::<Vec<_>>();
+        Ok(lu::unpack(&matrix, &pivots, m, n))
+    }
+}
+
+impl QR<f64> for Conventional<f64> {
+    fn decompose(&self, mode: Mode) -> Result<(Conventional<f64>, Conventional<f64>)> {
+        let (m, n) = (self.rows, self.columns);
+        let mut matrix = self.values.clone();
+        let mut taus = vec![0.0; min!(m, n)];
+        householder(&mut matrix, &mut taus, None, m, n)?;
+        orthogonal_upper(&matrix, &taus, m, n, mode)
+    }
+
+    fn decompose_pivoted(
+        &self,
+        mode: Mode,
+    ) -> Result<(Conventional<f64>, Conventional<f64>, Vec<usize>)> {
+        let (m, n) = (self.rows, self.columns);
+        let mut matrix = self.values.clone();
+        let mut taus = vec![0.0; min!(m, n)];
+        let mut pivots = vec![0; n];
+        householder(&mut matrix, &mut taus, Some(&mut pivots), m, n)?;
+        let (orthogonal, upper) = orthogonal_upper(&matrix, &taus, m, n, mode)?;
+        let permutation = pivots.iter().map(|&j| j as usize - 1).collect();
+        Ok((orthogonal, upper, permutation))
+    }
+}
+
 impl SingularValue<f64> for Conventional<f64> {
     fn decompose(&self) -> Result<(Conventional<f64>, Diagonal<f64>, Conventional<f64>)> {
         let (m, n) = (self.rows, self.columns);
@@ -42,6 +110,128 @@ impl SymmetricEigen<f64> for Conventional<f64> {
     }
 }
 
+fn lower_upper(matrix: &mut [f64], pivots: &mut [i32], m: usize, n: usize) -> Result<()> {
+    debug_assert_eq!(matrix.len(), m * n);
+    debug_assert_eq!(pivots.len(), min!(m, n));
+    let (m, n) = (m as i32, n as i32);
+    let mut info = 0;
+    unsafe {
+        backend::dgetrf(m, n, matrix, m, pivots, &mut info);
+    }
+    if info < 0 {
+        raise!(InvalidArgument { info });
+    } else if info > 0 {
+        raise!(Singular {
+            pivot: info as usize - 1,
+        });
+    }
+    Ok(())
+}
+
+fn general_eigen(
+    matrix: &mut [f64],
+    real: &mut [f64],
+    imaginary: &mut [f64],
+    left: Option<&mut [f64]>,
+    right: &mut [f64],
+    m: usize,
+) -> Result<()> {
+    debug_assert_eq!(matrix.len(), m * m);
+    debug_assert_eq!(real.len(), m);
+    debug_assert_eq!(imaginary.len(), m);
+    debug_assert_eq!(right.len(), m * m);
+    let (jobvl, mut dummy) = (if left.is_some() { b'V' } else { b'N' }, [0.0]);
+    let (left, ldvl) = match left {
+        Some(left) => (left, m),
+        None => (&mut dummy[..], 1),
+    };
+    let (m, ldvl) = (m as i32, ldvl as i32);
+    let mut info = 0;
+    let mut work = [0.0];
+    unsafe {
+        backend::dgeev(
+            jobvl, b'V', m, matrix, m, real, imaginary, left, ldvl, right, m, &mut work, -1,
+            &mut info,
+        );
+    }
+    success!(info);
+    let lwork = work[0] as i32;
+    let mut work = unsafe { buffer!(lwork) };
+    unsafe {
+        backend::dgeev(
+            jobvl, b'V', m, matrix, m, real, imaginary, left, ldvl, right, m, &mut work, lwork,
+            &mut info,
+        );
+    }
+    success!(info);
+    Ok(())
+}
+
+fn householder(
+    matrix: &mut [f64],
+    taus: &mut [f64],
+    mut pivots: Option<&mut [i32]>,
+    m: usize,
+    n: usize,
+) -> Result<()> {
+    debug_assert_eq!(matrix.len(), m * n);
+    debug_assert_eq!(taus.len(), min!(m, n));
+    let (m, n) = (m as i32, n as i32);
+    let mut info = 0;
+    let mut work = [0.0];
+    unsafe {
+        match pivots {
+            Some(ref mut pivots) => backend::dgeqp3(
+                m, n, matrix, m, pivots, taus, &mut work, -1, &mut info,
+            ),
+            None => backend::dgeqrf(m, n, matrix, m, taus, &mut work, -1, &mut info),
+        }
+    }
+    success!(info);
+    let lwork = work[0] as i32;
+    let mut work = unsafe { buffer!(lwork) };
+    unsafe {
+        match pivots {
+            Some(ref mut pivots) => backend::dgeqp3(
+                m, n, matrix, m, pivots, taus, &mut work, lwork, &mut info,
+            ),
+            None => backend::dgeqrf(m, n, matrix, m, taus, &mut work, lwork, &mut info),
+        }
+    }
+    success!(info);
+    Ok(())
+}
+
+fn orthogonal_upper(
+    matrix: &[f64],
+    taus: &[f64],
+    m: usize,
+    n: usize,
+    mode: Mode,
+) -> Result<(Conventional<f64>, Conventional<f64>)> {
+    let k = taus.len();
+    let q = match mode {
+        Mode::Economy => k,
+        Mode::Full => m,
+    };
+    let mut orthogonal = Conventional::new((m, q));
+    orthogonal.values[..(m * k)].copy_from_slice(&matrix[..(m * k)]);
+    let (m, q, k) = (m as i32, q as i32, k as i32);
+    let mut info = 0;
+    let mut work = [0.0];
+    unsafe {
+        backend::dorgqr(m, q, k, &mut orthogonal, m, taus, &mut work, -1, &mut info);
+    }
+    success!(info);
+    let lwork = work[0] as i32;
+    let mut work = unsafe { buffer!(lwork) };
+    unsafe {
+        backend::dorgqr(m, q, k, &mut orthogonal, m, taus, &mut work, lwork, &mut info);
+    }
+    success!(info);
+    Ok((orthogonal, qr::upper(matrix, m as usize, n, q as usize)))
+}
+
 fn singular_value(
     matrix: &[f64],
     left: &mut [f64],
@@ -0,0 +1,123 @@
+use num_traits::Float;
+
+use decomposition::Cholesky;
+use format::packed::Variant;
+use format::{Conventional, Packed};
+use {Element, Result};
+
+impl<T> Cholesky<T> for Conventional<T>
+where
+    T: Element + Float,
+{
+    fn decompose(&self, variant: Variant) -> Result<Packed<T>> {
+        let n = square!(self);
+        let mut matrix = self.values.clone();
+        if let Variant::Upper = variant {
+            for j in 0..n {
+                for i in (j + 1)..n {
+                    matrix[j * n + i] = matrix[i * n + j];
+                }
+            }
+        }
+        factorize(&mut matrix, n)?;
+        let mut values = Vec::with_capacity(n * (n + 1) / 2);
+        match variant {
+            Variant::Lower => {
+                for j in 0..n {
+                    values.extend_from_slice(&matrix[(j * n + j)..((j + 1) * n)]);
+                }
+            }
+            Variant::Upper => {
+                for j in 0..n {
+                    values.extend((0..(j + 1)).map(|i| matrix[i * n + j]));
+                }
+            }
+        }
+        Ok(Packed {
+            size: n,
+            variant,
+            values,
+        })
+    }
+}
+
+fn factorize<T>(matrix: &mut [T], n: usize) -> Result<()>
+where
+    T: Element + Float,
+{
+    debug_assert_eq!(matrix.len(), n * n);
+    for j in 0..n {
+        let pivot = matrix[j * n + j];
+        if pivot <= <T as Element>::zero() || pivot.is_nan() {
+            raise!(NotPositiveDefinite { pivot: j });
+        }
+        let pivot = pivot.sqrt();
+        matrix[j * n + j] = pivot;
+        for i in (j + 1)..n {
+            matrix[j * n + i] = matrix[j * n + i] / pivot;
+        }
+        let (head, tail) = matrix.split_at_mut((j + 1) * n);
+        let column = &head[(j * n)..];
+        for k in (j + 1)..n {
+            let factor = column[k];
+            let target = &mut tail[((k - j - 1) * n)..((k - j) * n)];
+            for i in k..n {
+                target[i] = target[i] - column[i] * factor;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use format::packed::Variant;
+    use prelude::*;
+
+    #[test]
+    fn decompose_lower() {
+        let matrix = Conventional::from_vec(
+            3,
+            matrix![
+                  4.0,  12.0, -16.0;
+                 12.0,  37.0, -43.0;
+                -16.0, -43.0,  98.0;
+            ],
+        );
+        let factor = Cholesky::decompose(&matrix, Variant::Lower).unwrap();
+        assert_eq!(factor.variant, Variant::Lower);
+        assert_eq!(&factor.values, &[2.0, 6.0, -8.0, 1.0, 5.0, 3.0]);
+    }
+
+    #[test]
+    fn decompose_upper() {
+        let matrix = Conventional::from_vec(
+            3,
+            matrix![
+                4.0, 12.0, -16.0;
+                0.0, 37.0, -43.0;
+                0.0,  0.0,  98.0;
+            ],
+        );
+        let factor = Cholesky::decompose(&matrix, Variant::Upper).unwrap();
+        assert_eq!(factor.variant, Variant::Upper);
+        assert_eq!(&factor.values, &[2.0, 6.0, 1.0, -8.0, 5.0, 3.0]);
+    }
+
+    #[test]
+    fn decompose_indefinite() {
+        let matrix = Conventional::from_vec(
+            3,
+            matrix![
+                4.0, 2.0, 0.0;
+                2.0, 1.0, 0.0;
+                0.0, 0.0, 1.0;
+            ],
+        );
+        let error = Cholesky::decompose(&matrix, Variant::Lower).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "encountered a nonpositive pivot in column 1"
+        );
+    }
+}
@@ -0,0 +1,741 @@
+use num_complex::Complex;
+use num_traits::Float;
+
+use decomposition::GeneralEigen;
+use format::{Conventional, Diagonal};
+use operation::Transpose;
+use {Element, Result};
+
+const ITERATIONS: usize = 30;
+
+macro_rules! implement(
+    ($kind:ty) => (
+        impl GeneralEigen<Complex<$kind>> for Conventional<$kind> {
+            #[inline]
+            fn decompose(
+                &self,
+            ) -> Result<(Diagonal<Complex<$kind>>, Conventional<Complex<$kind>>)> {
+                let (real, imaginary, vectors) = decompose(self)?;
+                Ok(compose(&real, &imaginary, &vectors, self.rows))
+            }
+
+            fn decompose_with_left(
+                &self,
+            ) -> Result<(
+                Diagonal<Complex<$kind>>,
+                Conventional<Complex<$kind>>,
+                Conventional<Complex<$kind>>,
+            )> {
+                let (values, right) = GeneralEigen::decompose(self)?;
+                let left = left(self, &values)?;
+                Ok((values, right, left))
+            }
+        }
+    );
+);
+
+implement!(f32);
+#[cfg(not(feature = "acceleration"))]
+implement!(f64);
+
+/// Assemble complex eigenvalues and eigenvectors from their real parts.
+///
+/// The eigenvectors are given in the representation of LAPACK: a real
+/// eigenvalue corresponds to a single real column, and a complex pair with the
+/// positive imaginary part first corresponds to two columns holding the real
+/// and imaginary parts of the first eigenvector. The eigenvectors are
+/// normalized as described in `GeneralEigen`.
+pub fn compose<T>(
+    real: &[T],
+    imaginary: &[T],
+    vectors: &[T],
+    m: usize,
+) -> (Diagonal<Complex<T>>, Conventional<Complex<T>>)
+where
+    T: Element + Float,
+    Complex<T>: Element,
+{
+    debug_assert_eq!(real.len(), m);
+    debug_assert_eq!(imaginary.len(), m);
+    debug_assert_eq!(vectors.len(), m * m);
+    let zero = <T as Element>::zero();
+    let values = real
+        .iter()
+        .zip(imaginary)
+        .map(|(&re, &im)| Complex::new(re, im))
+        .collect();
+    let mut result = Conventional::new(m);
+    let mut j = 0;
+    while j < m {
+        let column = &vectors[(j * m)..((j + 1) * m)];
+        if imaginary[j] > zero && j + 1 < m {
+            let next = &vectors[((j + 1) * m)..((j + 2) * m)];
+            {
+                let target = &mut result.values[(j * m)..((j + 1) * m)];
+                for ((target, &re), &im) in target.iter_mut().zip(column).zip(next) {
+                    *target = Complex::new(re, im);
+                }
+                normalize(target, true);
+            }
+            for i in 0..m {
+                result.values[(j + 1) * m + i] = result.values[j * m + i].conj();
+            }
+            j += 2;
+        } else {
+            let target = &mut result.values[(j * m)..((j + 1) * m)];
+            for (target, &re) in target.iter_mut().zip(column) {
+                *target = Complex::new(re, zero);
+            }
+            normalize(target, false);
+            j += 1;
+        }
+    }
+    (Diagonal::from_vec(m, values), result)
+}
+
+/// Perform the decomposition.
+///
+/// The matrix is reduced to the upper Hessenberg form by Householder
+/// transformations and then to the real Schur form by the shifted QR
+/// algorithm, after which the eigenvectors are obtained by back substitution.
+/// The result is in the representation expected by `compose`.
+fn decompose<T>(matrix: &Conventional<T>) -> Result<(Vec<T>, Vec<T>, Vec<T>)>
+where
+    T: Element + Float,
+{
+    let m = square!(matrix);
+    if matrix.values.iter().any(|value| !value.is_finite()) {
+        raise!("expected a matrix with finite elements");
+    }
+    let mut upper = matrix.clone();
+    let mut vectors = Conventional::new(m);
+    let mut real = vec![<T as Element>::zero(); m];
+    let mut imaginary = vec![<T as Element>::zero(); m];
+    if m > 0 {
+        reduce(&mut upper, &mut vectors);
+        triangularize(&mut upper, &mut vectors, &mut real, &mut imaginary)?;
+    }
+    Ok((real, imaginary, vectors.values))
+}
+
+/// Compute the left eigenvectors.
+///
+/// The left eigenvectors of a real matrix are the complex conjugates of the
+/// right eigenvectors of its transpose, which are matched with the given
+/// eigenvalues.
+fn left<T>(matrix: &Conventional<T>, values: &[Complex<T>]) -> Result<Conventional<Complex<T>>>
+where
+    T: Element + Float,
+    Complex<T>: Element,
+{
+    let m = values.len();
+    let (real, imaginary, vectors) = decompose(&matrix.transpose())?;
+    let (others, vectors) = compose(&real, &imaginary, &vectors, m);
+    let mut left = Conventional::new(m);
+    let mut taken = vec![false; m];
+    for (j, &value) in values.iter().enumerate() {
+        let mut k = m;
+        for l in (0..m).filter(|&l| !taken[l]) {
+            if k == m || (others[l] - value).norm_sqr() < (others[k] - value).norm_sqr() {
+                k = l;
+            }
+        }
+        taken[k] = true;
+        for i in 0..m {
+            left.values[j * m + i] = vectors.values[k * m + i].conj();
+        }
+    }
+    Ok(left)
+}
+
+/// Reduce a matrix to the upper Hessenberg form.
+///
+/// The transformation is accumulated in the second matrix, which is assumed
+/// to be zero on entry.
+fn reduce<T>(h: &mut Conventional<T>, v: &mut Conventional<T>)
+where
+    T: Element + Float,
+{
+    let n = h.rows;
+    let zero = <T as Element>::zero();
+    let mut ort = vec![zero; n];
+    for m in 1..(n - 1) {
+        let mut scale = zero;
+        for i in m..n {
+            scale = scale + h[(i, m - 1)].abs();
+        }
+        if Element::is_zero(&scale) {
+            continue;
+        }
+        let mut sum = zero;
+        for i in (m..n).rev() {
+            ort[i] = h[(i, m - 1)] / scale;
+            sum = sum + ort[i] * ort[i];
+        }
+        let mut g = sum.sqrt();
+        if ort[m] > zero {
+            g = -g;
+        }
+        sum = sum - ort[m] * g;
+        ort[m] = ort[m] - g;
+        for j in m..n {
+            let mut f = zero;
+            for i in (m..n).rev() {
+                f = f + ort[i] * h[(i, j)];
+            }
+            f = f / sum;
+            for i in m..n {
+                h[(i, j)] = h[(i, j)] - f * ort[i];
+            }
+        }
+        for i in 0..n {
+            let mut f = zero;
+            for j in (m..n).rev() {
+                f = f + ort[j] * h[(i, j)];
+            }
+            f = f / sum;
+            for j in m..n {
+                h[(i, j)] = h[(i, j)] - f * ort[j];
+            }
+        }
+        ort[m] = scale * ort[m];
+        h[(m, m - 1)] = scale * g;
+    }
+    for i in 0..n {
+        v[(i, i)] = T::one();
+    }
+    for m in (1..(n - 1)).rev() {
+        if Element::is_zero(&h[(m, m - 1)]) {
+            continue;
+        }
+        for i in (m + 1)..n {
+            ort[i] = h[(i, m - 1)];
+        }
+        for j in m..n {
+            let mut g = zero;
+            for i in m..n {
+                g = g + ort[i] * v[(i, j)];
+            }
+            g = (g / ort[m]) / h[(m, m - 1)];
+            for i in m..n {
+                v[(i, j)] = v[(i, j)] + g * ort[i];
+            }
+        }
+    }
+    for j in 0..n {
+        for i in (j + 2)..n {
+            h[(i, j)] = zero;
+        }
+    }
+}
+
+/// Reduce an upper Hessenberg matrix to the real Schur form and compute the
+/// eigenvectors.
+///
+/// The real and imaginary parts of the eigenvalues are written to `d` and
+/// `e`, respectively, and the eigenvectors replace the accumulated
+/// transformation in the second matrix.
+fn triangularize<T>(
+    h: &mut Conventional<T>,
+    v: &mut Conventional<T>,
+    d: &mut [T],
+    e: &mut [T],
+) -> Result<()>
+where
+    T: Element + Float,
+{
+    let nn = h.rows;
+    let zero = <T as Element>::zero();
+    let one = T::one();
+    let two = one + one;
+    let eps = T::epsilon();
+    let limit = ITERATIONS * if nn > 10 { nn } else { 10 };
+
+    let mut norm = zero;
+    for i in 0..nn {
+        for j in (if i > 0 { i - 1 } else { 0 })..nn {
+            norm = norm + h[(i, j)].abs();
+        }
+    }
+
+    let (mut r, mut s, mut z) = (zero, zero, zero);
+    let (mut p, mut q, mut w, mut x, mut y);
+    let mut shift = zero;
+    let mut iterations = 0;
+    let mut n = nn - 1;
+    loop {
+        let mut l = n;
+        while l > 0 {
+            s = h[(l - 1, l - 1)].abs() + h[(l, l)].abs();
+            if Element::is_zero(&s) {
+                s = norm;
+            }
+            if h[(l, l - 1)].abs() < eps * s {
+                break;
+            }
+            l -= 1;
+        }
+        if l == n {
+            h[(n, n)] = h[(n, n)] + shift;
+            d[n] = h[(n, n)];
+            e[n] = zero;
+            iterations = 0;
+            if n == 0 {
+                break;
+            }
+            n -= 1;
+        } else if l == n - 1 {
+            w = h[(n, n - 1)] * h[(n - 1, n)];
+            p = (h[(n - 1, n - 1)] - h[(n, n)]) / two;
+            q = p * p + w;
+            z = q.abs().sqrt();
+            h[(n, n)] = h[(n, n)] + shift;
+            h[(n - 1, n - 1)] = h[(n - 1, n - 1)] + shift;
+            x = h[(n, n)];
+            if q >= zero {
+                z = if p >= zero { p + z } else { p - z };
+                d[n - 1] = x + z;
+                d[n] = d[n - 1];
+                if !Element::is_zero(&z) {
+                    d[n] = x - w / z;
+                }
+                e[n - 1] = zero;
+                e[n] = zero;
+                x = h[(n, n - 1)];
+                s = x.abs() + z.abs();
+                p = x / s;
+                q = z / s;
+                r = (p * p + q * q).sqrt();
+                p = p / r;
+                q = q / r;
+                for j in (n - 1)..nn {
+                    z = h[(n - 1, j)];
+                    h[(n - 1, j)] = q * z + p * h[(n, j)];
+                    h[(n, j)] = q * h[(n, j)] - p * z;
+                }
+                for i in 0..(n + 1) {
+                    z = h[(i, n - 1)];
+                    h[(i, n - 1)] = q * z + p * h[(i, n)];
+                    h[(i, n)] = q * h[(i, n)] - p * z;
+                }
+                for i in 0..nn {
+                    z = v[(i, n - 1)];
+                    v[(i, n - 1)] = q * z + p * v[(i, n)];
+                    v[(i, n)] = q * v[(i, n)] - p * z;
+                }
+            } else {
+                d[n - 1] = x + p;
+                d[n] = x + p;
+                e[n - 1] = z;
+                e[n] = -z;
+            }
+            iterations = 0;
+            if n == 1 {
+                break;
+            }
+            n -= 2;
+        } else {
+            x = h[(n, n)];
+            y = h[(n - 1, n - 1)];
+            w = h[(n, n - 1)] * h[(n - 1, n)];
+            if iterations == 10 {
+                shift = shift + x;
+                for i in 0..(n + 1) {
+                    h[(i, i)] = h[(i, i)] - x;
+                }
+                s = h[(n, n - 1)].abs() + h[(n - 1, n - 2)].abs();
+                x = T::from(0.75).unwrap() * s;
+                y = x;
+                w = T::from(-0.4375).unwrap() * s * s;
+            }
+            if iterations == 30 {
+                s = (y - x) / two;
+                s = s * s + w;
+                if s > zero {
+                    s = s.sqrt();
+                    if y < x {
+                        s = -s;
+                    }
+                    s = x - w / ((y - x) / two + s);
+                    for i in 0..(n + 1) {
+                        h[(i, i)] = h[(i, i)] - s;
+                    }
+                    shift = shift + s;
+                    x = T::from(0.964).unwrap();
+                    y = x;
+                    w = x;
+                }
+            }
+            iterations += 1;
+            if iterations > limit {
+                raise!(NonConvergence {
+                    iterations: Some(limit),
+                });
+            }
+            let mut m = n - 2;
+            loop {
+                z = h[(m, m)];
+                r = x - z;
+                s = y - z;
+                p = (r * s - w) / h[(m + 1, m)] + h[(m, m + 1)];
+                q = h[(m + 1, m + 1)] - z - r - s;
+                r = h[(m + 2, m + 1)];
+                s = p.abs() + q.abs() + r.abs();
+                p = p / s;
+                q = q / s;
+                r = r / s;
+                if m == l {
+                    break;
+                }
+                let left = h[(m, m - 1)].abs() * (q.abs() + r.abs());
+                let right = p.abs() * (h[(m - 1, m - 1)].abs() + z.abs() + h[(m + 1, m + 1)].abs());
+                if left < eps * right {
+                    break;
+                }
+                m -= 1;
+            }
+            for i in (m + 2)..(n + 1) {
+                h[(i, i - 2)] = zero;
+                if i > m + 2 {
+                    h[(i, i - 3)] = zero;
+                }
+            }
+            for k in m..n {
+                let last = k == n - 1;
+                if k != m {
+                    p = h[(k, k - 1)];
+                    q = h[(k + 1, k - 1)];
+                    r = if last { zero } else { h[(k + 2, k - 1)] };
+                    x = p.abs() + q.abs() + r.abs();
+                    if Element::is_zero(&x) {
+                        continue;
+                    }
+                    p = p / x;
+                    q = q / x;
+                    r = r / x;
+                }
+                s = (p * p + q * q + r * r).sqrt();
+                if p < zero {
+                    s = -s;
+                }
+                if Element::is_zero(&s) {
+                    continue;
+                }
+                if k != m {
+                    h[(k, k - 1)] = -s * x;
+                } else if l != m {
+                    h[(k, k - 1)] = -h[(k, k - 1)];
+                }
+                p = p + s;
+                x = p / s;
+                y = q / s;
+                z = r / s;
+                q = q / p;
+                r = r / p;
+                for j in k..nn {
+                    p = h[(k, j)] + q * h[(k + 1, j)];
+                    if !last {
+                        p = p + r * h[(k + 2, j)];
+                        h[(k + 2, j)] = h[(k + 2, j)] - p * z;
+                    }
+                    h[(k, j)] = h[(k, j)] - p * x;
+                    h[(k + 1, j)] = h[(k + 1, j)] - p * y;
+                }
+                for i in 0..(min!(n, k + 3) + 1) {
+                    p = x * h[(i, k)] + y * h[(i, k + 1)];
+                    if !last {
+                        p = p + z * h[(i, k + 2)];
+                        h[(i, k + 2)] = h[(i, k + 2)] - p * r;
+                    }
+                    h[(i, k)] = h[(i, k)] - p;
+                    h[(i, k + 1)] = h[(i, k + 1)] - p * q;
+                }
+                for i in 0..nn {
+                    p = x * v[(i, k)] + y * v[(i, k + 1)];
+                    if !last {
+                        p = p + z * v[(i, k + 2)];
+                        v[(i, k + 2)] = v[(i, k + 2)] - p * r;
+                    }
+                    v[(i, k)] = v[(i, k)] - p;
+                    v[(i, k + 1)] = v[(i, k + 1)] - p * q;
+                }
+            }
+        }
+    }
+
+    if Element::is_zero(&norm) {
+        return Ok(());
+    }
+    for n in (0..nn).rev() {
+        p = d[n];
+        q = e[n];
+        if Element::is_zero(&q) {
+            let mut l = n;
+            h[(n, n)] = one;
+            for i in (0..n).rev() {
+                w = h[(i, i)] - p;
+                r = zero;
+                for j in l..(n + 1) {
+                    r = r + h[(i, j)] * h[(j, n)];
+                }
+                if e[i] < zero {
+                    z = w;
+                    s = r;
+                    continue;
+                }
+                l = i;
+                if Element::is_zero(&e[i]) {
+                    h[(i, n)] = if Element::is_zero(&w) {
+                        -r / (eps * norm)
+                    } else {
+                        -r / w
+                    };
+                } else {
+                    x = h[(i, i + 1)];
+                    y = h[(i + 1, i)];
+                    q = (d[i] - p) * (d[i] - p) + e[i] * e[i];
+                    let t = (x * s - z * r) / q;
+                    h[(i, n)] = t;
+                    h[(i + 1, n)] = if x.abs() > z.abs() {
+                        (-r - w * t) / x
+                    } else {
+                        (-s - y * t) / z
+                    };
+                }
+                let t = h[(i, n)].abs();
+                if eps * t * t > one {
+                    for j in i..(n + 1) {
+                        h[(j, n)] = h[(j, n)] / t;
+                    }
+                }
+            }
+        } else if q < zero {
+            let mut l = n - 1;
+            if h[(n, n - 1)].abs() > h[(n - 1, n)].abs() {
+                h[(n - 1, n - 1)] = q / h[(n, n - 1)];
+                h[(n - 1, n)] = -(h[(n, n)] - p) / h[(n, n - 1)];
+            } else {
+                let (re, im) = divide(zero, -h[(n - 1, n)], h[(n - 1, n - 1)] - p, q);
+                h[(n - 1, n - 1)] = re;
+                h[(n - 1, n)] = im;
+            }
+            h[(n, n - 1)] = zero;
+            h[(n, n)] = one;
+            for i in (0..(n - 1)).rev() {
+                let (mut ra, mut sa) = (zero, zero);
+                for j in l..(n + 1) {
+                    ra = ra + h[(i, j)] * h[(j, n - 1)];
+                    sa = sa + h[(i, j)] * h[(j, n)];
+                }
+                w = h[(i, i)] - p;
+                if e[i] < zero {
+                    z = w;
+                    r = ra;
+                    s = sa;
+                    continue;
+                }
+                l = i;
+                if Element::is_zero(&e[i]) {
+                    let (re, im) = divide(-ra, -sa, w, q);
+                    h[(i, n - 1)] = re;
+                    h[(i, n)] = im;
+                } else {
+                    x = h[(i, i + 1)];
+                    y = h[(i + 1, i)];
+                    let mut vr = (d[i] - p) * (d[i] - p) + e[i] * e[i] - q * q;
+                    let vi = (d[i] - p) * two * q;
+                    if Element::is_zero(&vr) && Element::is_zero(&vi) {
+                        vr = eps * norm * (w.abs() + q.abs() + x.abs() + y.abs() + z.abs());
+                    }
+                    let (re, im) = divide(x * r - z * ra + q * sa, x * s - z * sa - q * ra, vr, vi);
+                    h[(i, n - 1)] = re;
+                    h[(i, n)] = im;
+                    if x.abs() > z.abs() + q.abs() {
+                        h[(i + 1, n - 1)] = (-ra - w * h[(i, n - 1)] + q * h[(i, n)]) / x;
+                        h[(i + 1, n)] = (-sa - w * h[(i, n)] - q * h[(i, n - 1)]) / x;
+                    } else {
+                        let (re, im) = divide(-r - y * h[(i, n - 1)], -s - y * h[(i, n)], z, q);
+                        h[(i + 1, n - 1)] = re;
+                        h[(i + 1, n)] = im;
+                    }
+                }
+                let t = h[(i, n - 1)].abs().max(h[(i, n)].abs());
+                if eps * t * t > one {
+                    for j in i..(n + 1) {
+                        h[(j, n - 1)] = h[(j, n - 1)] / t;
+                        h[(j, n)] = h[(j, n)] / t;
+                    }
+                }
+            }
+        }
+    }
+    for j in (0..nn).rev() {
+        for i in 0..nn {
+            z = zero;
+            for k in 0..(j + 1) {
+                z = z + v[(i, k)] * h[(k, j)];
+            }
+            v[(i, j)] = z;
+        }
+    }
+    Ok(())
+}
+
+/// Divide two complex numbers given by their real and imaginary parts.
+fn divide<T>(xr: T, xi: T, yr: T, yi: T) -> (T, T)
+where
+    T: Element + Float,
+{
+    if yr.abs() > yi.abs() {
+        let r = yi / yr;
+        let d = yr + r * yi;
+        ((xr + r * xi) / d, (xi - r * xr) / d)
+    } else {
+        let r = yr / yi;
+        let d = yi + r * yr;
+        ((r * xr + xi) / d, (r * xi - xr) / d)
+    }
+}
+
+/// Scale a vector to unit norm and, if requested, rotate it so that its
+/// largest component is real.
+fn normalize<T>(vector: &mut [Complex<T>], rotate: bool)
+where
+    T: Element + Float,
+{
+    let norm = vector.iter().fold(<T as Element>::zero(), |sum, value| {
+        sum.hypot(value.re).hypot(value.im)
+    });
+    if Element::is_zero(&norm) {
+        return;
+    }
+    let mut factor = Complex::new(norm.recip(), <T as Element>::zero());
+    if rotate {
+        let mut k = 0;
+        for i in 1..vector.len() {
+            if vector[i].norm_sqr() > vector[k].norm_sqr() {
+                k = i;
+            }
+        }
+        factor = factor * vector[k].conj() / vector[k].re.hypot(vector[k].im);
+    }
+    for value in vector.iter_mut() {
+        *value = *value * factor;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use num_complex::Complex;
+
+    use assert;
+    use c64;
+    use prelude::*;
+
+    fn check(matrix: &Conventional<f64>, delta: f64) {
+        let m = matrix.rows;
+        let (values, right, left) = GeneralEigen::decompose_with_left(matrix).unwrap();
+        for j in 0..m {
+            for vectors in &[&right, &left] {
+                let norm = (0..m).fold(0.0, |sum: f64, i| sum + vectors[(i, j)].norm_sqr());
+                assert::close(norm.sqrt(), 1.0, delta);
+            }
+            for i in 0..m {
+                let (mut product, mut other) = (c64::new(0.0, 0.0), c64::new(0.0, 0.0));
+                for k in 0..m {
+                    product += right[(k, j)] * matrix[(i, k)];
+                    other += left[(k, j)].conj() * matrix[(k, i)];
+                }
+                assert!((product - values[j] * right[(i, j)]).norm_sqr().sqrt() < delta);
+                assert!((other - values[j] * left[(i, j)].conj()).norm_sqr().sqrt() < delta);
+            }
+        }
+    }
+
+    #[test]
+    fn decompose_real() {
+        let matrix = Conventional::from_vec(
+            3,
+            matrix![
+                1.0f64, 2.0, 3.0;
+                0.0,    4.0, 5.0;
+                0.0,    0.0, 6.0;
+            ],
+        );
+        let (values, vectors) = GeneralEigen::decompose(&matrix).unwrap();
+        let mut values = values.iter().map(|value| value.re).collect::<Vec<_>>();
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        assert::close(&values, &[1.0, 4.0, 6.0][..], 1e-14);
+        assert!(vectors.iter().all(|value| value.im == 0.0));
+        check(&matrix, 1e-13);
+    }
+
+    #[test]
+    fn decompose_complex() {
+        let matrix = Conventional::from_vec(2, vec![0.0f64, 1.0, -1.0, 0.0]);
+        let (values, vectors) = GeneralEigen::decompose(&matrix).unwrap();
+        assert::close(values[0].re, 0.0, 1e-15);
+        assert::close(values[0].im, 1.0, 1e-15);
+        assert_eq!(values[1], values[0].conj());
+        assert_eq!(vectors[(0, 1)], vectors[(0, 0)].conj());
+        assert_eq!(vectors[(1, 1)], vectors[(1, 0)].conj());
+        check(&matrix, 1e-14);
+    }
+
+    #[test]
+    fn decompose_mixed() {
+        let matrix = Conventional::from_vec(
+            4,
+            matrix![
+                 4.0f64, -5.0,  0.0,  3.0;
+                 0.0,  4.0, -3.0, -5.0;
+                 5.0, -3.0,  4.0,  0.0;
+                 3.0,  0.0,  5.0,  4.0;
+            ],
+        );
+        let (values, _) = GeneralEigen::decompose(&matrix).unwrap();
+        let mut values = values
+            .iter()
+            .map(|value| (value.re, value.im))
+            .collect::<Vec<_>>();
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        let expected = [(1.0, -5.0), (1.0, 5.0), (2.0, 0.0), (12.0, 0.0)];
+        for (value, expected) in values.iter().zip(&expected) {
+            assert::close(value.0, expected.0, 1e-13);
+            assert::close(value.1, expected.1, 1e-13);
+        }
+        check(&matrix, 1e-12);
+    }
+
+    #[test]
+    fn decompose_general() {
+        let matrix = Conventional::from_vec(
+            5,
+            matrix![
+                 0.5,  1.0, -2.0,  0.3,  4.0;
+                -1.5,  2.0,  0.7, -3.0,  1.0;
+                 2.2, -0.4,  1.0,  0.0, -1.0;
+                 0.0,  3.3, -2.1,  0.9,  0.6;
+                 1.0,  0.0,  0.5, -0.8,  2.5;
+            ],
+        );
+        check(&matrix, 1e-12);
+    }
+
+    #[test]
+    fn decompose_nonfinite() {
+        let matrix = Conventional::from_vec(2, vec![1.0f32, f32::INFINITY, 0.0, 1.0]);
+        assert!(GeneralEigen::decompose(&matrix).is_err());
+    }
+
+    #[test]
+    fn decompose_single() {
+        let matrix = Conventional::from_vec(2, vec![1.0f32, 0.0, 0.0, 2.0]);
+        let (values, vectors) = GeneralEigen::decompose(&matrix).unwrap();
+        assert_eq!(&*values, &[Complex::new(1.0, 0.0), Complex::new(2.0, 0.0)]);
+        assert_eq!(vectors[(0, 0)].norm_sqr(), 1.0);
+        assert_eq!(vectors[(1, 1)].norm_sqr(), 1.0);
+    }
+}
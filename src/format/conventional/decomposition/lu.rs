@@ -0,0 +1,225 @@
+use num_traits::Float;
+
+use decomposition::LU;
+use format::Conventional;
+use {Element, Result};
+
+macro_rules! implement(
+    ($kind:ty) => (
+        impl LU<$kind> for Conventional<$kind> {
+            fn decompose(&self) -> Result<(Conventional<$kind>, Conventional<$kind>, Vec<usize>)> {
+                let (m, n) = (self.rows, self.columns);
+                let mut matrix = self.values.clone();
+                let mut pivots = vec![0; min!(m, n)];
+                factorize(&mut matrix, &mut pivots, m, n)?;
+                Ok(unpack(&matrix, &pivots, m, n))
+            }
+        }
+    );
+);
+
+implement!(f32);
+#[cfg(not(feature = "acceleration"))]
+implement!(f64);
+
+/// Factorize a matrix in place.
+///
+/// The strictly lower triangle is overwritten with the multipliers and the
+/// upper triangle with the upper-triangular factor. The `k`th row was
+/// interchanged with the `pivots[k]`th row at step `k`.
+pub fn factorize<T>(matrix: &mut [T], pivots: &mut [usize], m: usize, n: usize) -> Result<()>
+where
+    T: Element + Float,
+{
+    debug_assert_eq!(matrix.len(), m * n);
+    debug_assert_eq!(pivots.len(), min!(m, n));
+    for k in 0..min!(m, n) {
+        let (mut p, mut maximum) = (k, matrix[k * m + k].abs());
+        for i in (k + 1)..m {
+            let value = matrix[k * m + i].abs();
+            if value > maximum {
+                p = i;
+                maximum = value;
+            }
+        }
+        pivots[k] = p;
+        if Element::is_zero(&maximum) {
+            raise!(Singular { pivot: k });
+        }
+        if p != k {
+            for j in 0..n {
+                matrix.swap(j * m + k, j * m + p);
+            }
+        }
+        let pivot = matrix[k * m + k];
+        for i in (k + 1)..m {
+            matrix[k * m + i] = matrix[k * m + i] / pivot;
+        }
+        for j in (k + 1)..n {
+            let factor = matrix[j * m + k];
+            if Element::is_zero(&factor) {
+                continue;
+            }
+            for i in (k + 1)..m {
+                matrix[j * m + i] = matrix[j * m + i] - matrix[k * m + i] * factor;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Split a factorized matrix into its factors and a permutation.
+pub fn unpack<T>(
+    matrix: &[T],
+    pivots: &[usize],
+    m: usize,
+    n: usize,
+) -> (Conventional<T>, Conventional<T>, Vec<usize>)
+where
+    T: Element + Float,
+{
+    let k = min!(m, n);
+    let mut lower = Conventional::new((m, k));
+    for j in 0..k {
+        lower.values[j * m + j] = T::one();
+        lower.values[(j * m + j + 1)..((j + 1) * m)]
+            .copy_from_slice(&matrix[(j * m + j + 1)..((j + 1) * m)]);
+    }
+    let mut upper = Conventional::new((k, n));
+    for j in 0..n {
+        let rows = min!(j + 1, k);
+        upper.values[(j * k)..(j * k + rows)].copy_from_slice(&matrix[(j * m)..(j * m + rows)]);
+    }
+    (lower, upper, permute(pivots, m))
+}
+
+/// Solve systems in place given a factorized matrix.
+///
+/// The right-hand sides are stored in the column-major order and are
+/// overwritten with the solutions.
+pub fn substitute<T>(matrix: &[T], pivots: &[usize], values: &mut [T], m: usize, n: usize)
+where
+    T: Element + Float,
+{
+    debug_assert_eq!(matrix.len(), m * m);
+    debug_assert_eq!(pivots.len(), m);
+    debug_assert_eq!(values.len(), m * n);
+    if m == 0 {
+        return;
+    }
+    for values in values.chunks_mut(m) {
+        for (k, &p) in pivots.iter().enumerate() {
+            values.swap(k, p);
+        }
+        for k in 0..m {
+            let value = values[k];
+            for i in (k + 1)..m {
+                values[i] = values[i] - matrix[k * m + i] * value;
+            }
+        }
+        for k in (0..m).rev() {
+            values[k] = values[k] / matrix[k * m + k];
+            let value = values[k];
+            for i in 0..k {
+                values[i] = values[i] - matrix[k * m + i] * value;
+            }
+        }
+    }
+}
+
+/// Convert a sequence of row interchanges into a permutation.
+pub fn permute(pivots: &[usize], m: usize) -> Vec<usize> {
+    let mut permutation = (0..m).collect::<Vec<_>>();
+    for (k, &p) in pivots.iter().enumerate() {
+        permutation.swap(k, p);
+    }
+    permutation
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    #[test]
+    fn decompose() {
+        let matrix = Conventional::from_vec(
+            3,
+            matrix![
+                1.0, 2.0,  3.0;
+                4.0, 5.0,  6.0;
+                7.0, 8.0, 10.0;
+            ],
+        );
+        let (lower, upper, permutation) = LU::decompose(&matrix).unwrap();
+        assert::close(
+            &*lower,
+            &*matrix![
+                1.0,       0.0, 0.0;
+                1.0 / 7.0, 1.0, 0.0;
+                4.0 / 7.0, 0.5, 1.0;
+            ],
+            1e-14,
+        );
+        assert::close(
+            &*upper,
+            &*matrix![
+                7.0, 8.0,       10.0;
+                0.0, 6.0 / 7.0, 11.0 / 7.0;
+                0.0, 0.0,       -0.5;
+            ],
+            1e-14,
+        );
+        assert_eq!(&permutation, &[2, 0, 1]);
+    }
+
+    #[test]
+    fn decompose_tall() {
+        let matrix = Conventional::from_vec(
+            (4, 2),
+            matrix![
+                1.0, 2.0;
+                3.0, 4.0;
+                5.0, 6.0;
+                8.0, 7.0;
+            ],
+        );
+        let (lower, upper, permutation) = LU::decompose(&matrix).unwrap();
+        assert_eq!((lower.rows, lower.columns), (4, 2));
+        assert_eq!((upper.rows, upper.columns), (2, 2));
+        let product = lower.multiply(&upper);
+        for i in 0..4 {
+            for j in 0..2 {
+                assert::close(product[(i, j)], matrix[(permutation[i], j)], 1e-14);
+            }
+        }
+    }
+
+    #[test]
+    fn decompose_wide() {
+        let matrix = Conventional::from_vec(
+            (2, 3),
+            matrix![
+                1.0f32, 2.0, 3.0;
+                4.0,    5.0, 6.0;
+            ],
+        );
+        let (lower, upper, permutation) = LU::decompose(&matrix).unwrap();
+        assert_eq!(&*lower, &[1.0, 0.25, 0.0, 1.0]);
+        assert::close(&*upper, &*matrix![4.0, 5.0, 6.0; 0.0, 0.75, 1.5;], 1e-6);
+        assert_eq!(&permutation, &[1, 0]);
+    }
+
+    #[test]
+    fn decompose_singular() {
+        let matrix = Conventional::from_vec(
+            3,
+            matrix![
+                1.0, 2.0, 3.0;
+                2.0, 4.0, 6.0;
+                1.0, 1.0, 1.0;
+            ],
+        );
+        assert!(LU::decompose(&matrix).is_err());
+    }
+}
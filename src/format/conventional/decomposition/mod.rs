@@ -1,2 +1,10 @@
 #[cfg(feature = "acceleration")]
 mod acceleration;
+
+mod cholesky;
+mod general_eigen;
+mod singular_value;
+mod symmetric_eigen;
+
+pub mod lu;
+pub mod qr;
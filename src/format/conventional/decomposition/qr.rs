@@ -0,0 +1,302 @@
+use num_traits::Float;
+
+use decomposition::{Mode, QR};
+use format::Conventional;
+use {Element, Result};
+
+macro_rules! implement(
+    ($kind:ty) => (
+        impl QR<$kind> for Conventional<$kind> {
+            fn decompose(&self, mode: Mode) -> Result<(Conventional<$kind>, Conventional<$kind>)> {
+                let (m, n) = (self.rows, self.columns);
+                let mut matrix = self.values.clone();
+                let mut taus = vec![0.0; min!(m, n)];
+                factorize(&mut matrix, &mut taus, None, m, n);
+                Ok(unpack(&matrix, &taus, m, n, mode))
+            }
+
+            fn decompose_pivoted(
+                &self,
+                mode: Mode,
+            ) -> Result<(Conventional<$kind>, Conventional<$kind>, Vec<usize>)> {
+                let (m, n) = (self.rows, self.columns);
+                let mut matrix = self.values.clone();
+                let mut taus = vec![0.0; min!(m, n)];
+                let mut permutation = (0..n).collect::<Vec<_>>();
+                factorize(&mut matrix, &mut taus, Some(&mut permutation), m, n);
+                let (orthogonal, upper) = unpack(&matrix, &taus, m, n, mode);
+                Ok((orthogonal, upper, permutation))
+            }
+        }
+    );
+);
+
+implement!(f32);
+#[cfg(not(feature = "acceleration"))]
+implement!(f64);
+
+/// Factorize a matrix in place.
+///
+/// The upper triangle is overwritten with the triangular factor, and the
+/// strictly lower triangle with the Householder vectors whose leading ones are
+/// implicit. The representation is the same as the one of LAPACK. If a
+/// permutation is given, the columns are pivoted, and the permutation is
+/// updated accordingly.
+pub fn factorize<T>(
+    matrix: &mut [T],
+    taus: &mut [T],
+    mut permutation: Option<&mut [usize]>,
+    m: usize,
+    n: usize,
+) where
+    T: Element + Float,
+{
+    debug_assert_eq!(matrix.len(), m * n);
+    debug_assert_eq!(taus.len(), min!(m, n));
+    for k in 0..min!(m, n) {
+        if let Some(ref mut permutation) = permutation {
+            let mut p = k;
+            let mut maximum = norm(&matrix[(k * m + k)..((k + 1) * m)]);
+            for j in (k + 1)..n {
+                let value = norm(&matrix[(j * m + k)..((j + 1) * m)]);
+                if value > maximum {
+                    p = j;
+                    maximum = value;
+                }
+            }
+            if p != k {
+                for i in 0..m {
+                    matrix.swap(k * m + i, p * m + i);
+                }
+                permutation.swap(k, p);
+            }
+        }
+        taus[k] = reflect(&mut matrix[(k * m + k)..((k + 1) * m)]);
+        if Element::is_zero(&taus[k]) {
+            continue;
+        }
+        let (head, tail) = matrix.split_at_mut((k + 1) * m);
+        let vector = &head[(k * m + k)..];
+        for column in tail.chunks_mut(m) {
+            apply(vector, taus[k], &mut column[k..]);
+        }
+    }
+}
+
+/// Extract the factors from a factorized matrix.
+pub fn unpack<T>(
+    matrix: &[T],
+    taus: &[T],
+    m: usize,
+    n: usize,
+    mode: Mode,
+) -> (Conventional<T>, Conventional<T>)
+where
+    T: Element + Float,
+{
+    let q = match mode {
+        Mode::Economy => min!(m, n),
+        Mode::Full => m,
+    };
+    let mut orthogonal = Conventional::new((m, q));
+    for j in 0..q {
+        orthogonal.values[j * m + j] = T::one();
+    }
+    for k in (0..taus.len()).rev() {
+        if Element::is_zero(&taus[k]) {
+            continue;
+        }
+        let vector = &matrix[(k * m + k)..((k + 1) * m)];
+        for column in orthogonal.values[(k * m)..].chunks_mut(m) {
+            apply(vector, taus[k], &mut column[k..]);
+        }
+    }
+    (orthogonal, upper(matrix, m, n, q))
+}
+
+/// Extract the upper-triangular factor with a given number of rows.
+pub fn upper<T>(matrix: &[T], m: usize, n: usize, rows: usize) -> Conventional<T>
+where
+    T: Element,
+{
+    let mut upper = Conventional::new((rows, n));
+    for j in 0..n {
+        let count = min!(j + 1, min!(m, rows));
+        upper.values[(j * rows)..(j * rows + count)]
+            .copy_from_slice(&matrix[(j * m)..(j * m + count)]);
+    }
+    upper
+}
+
+/// Compute an elementary reflector annihilating all but the first element.
+///
+/// The first element is overwritten with the resulting value, and the rest
+/// with the reflector scaled to have a unit first element. The returned value
+/// is the scalar factor of the reflector.
+fn reflect<T>(vector: &mut [T]) -> T
+where
+    T: Element + Float,
+{
+    let alpha = vector[0];
+    let rest = norm(&vector[1..]);
+    if Element::is_zero(&rest) {
+        return <T as Element>::zero();
+    }
+    let beta = -alpha.hypot(rest).copysign(alpha);
+    let scale = T::one() / (alpha - beta);
+    for value in &mut vector[1..] {
+        *value = *value * scale;
+    }
+    vector[0] = beta;
+    (beta - alpha) / beta
+}
+
+/// Apply an elementary reflector with an implicit unit first element.
+pub fn apply<T>(vector: &[T], tau: T, column: &mut [T])
+where
+    T: Element + Float,
+{
+    let mut sum = column[0];
+    for (&v, &c) in vector[1..].iter().zip(&column[1..]) {
+        sum = sum + v * c;
+    }
+    let sum = sum * tau;
+    column[0] = column[0] - sum;
+    for (&v, c) in vector[1..].iter().zip(&mut column[1..]) {
+        *c = *c - sum * v;
+    }
+}
+
+fn norm<T>(vector: &[T]) -> T
+where
+    T: Element + Float,
+{
+    let scale = vector
+        .iter()
+        .fold(<T as Element>::zero(), |maximum, &value| maximum.max(value.abs()));
+    if Element::is_zero(&scale) {
+        return scale;
+    }
+    let sum = vector.iter().fold(<T as Element>::zero(), |sum, &value| {
+        let value = value / scale;
+        sum + value * value
+    });
+    scale * sum.sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    use decomposition::Mode;
+
+    #[test]
+    fn decompose_economy() {
+        let matrix = Conventional::from_vec(
+            (4, 2),
+            matrix![
+                1.0, 2.0;
+                3.0, 4.0;
+                5.0, 6.0;
+                7.0, 8.0;
+            ],
+        );
+        let (orthogonal, upper) = QR::decompose(&matrix, Mode::Economy).unwrap();
+        assert_eq!((orthogonal.rows, orthogonal.columns), (4, 2));
+        assert_eq!((upper.rows, upper.columns), (2, 2));
+        assert::close(
+            &*upper,
+            &*matrix![
+                -9.16515138991168, -1.091089451179962e+01;
+                0.0, -9.759000729485331e-01;
+            ],
+            1e-13,
+        );
+        assert::close(
+            &*orthogonal,
+            &*matrix![
+                -1.091089451179962e-01, -8.29515062006252e-01;
+                -3.273268353539886e-01, -4.391550328268398e-01;
+                -5.45544725589981e-01, -4.879500364742661e-02;
+                -7.637626158259734e-01,  3.415650255319866e-01;
+            ],
+            1e-13,
+        );
+    }
+
+    #[test]
+    fn decompose_full() {
+        let matrix = Conventional::from_vec(
+            (4, 2),
+            matrix![
+                1.0, 2.0;
+                3.0, 4.0;
+                5.0, 6.0;
+                7.0, 8.0;
+            ],
+        );
+        let (orthogonal, upper) = QR::decompose(&matrix, Mode::Full).unwrap();
+        assert_eq!((orthogonal.rows, orthogonal.columns), (4, 4));
+        assert_eq!((upper.rows, upper.columns), (4, 2));
+        assert::close(&upper.values[2..4], &[0.0, 0.0][..], 0.0);
+        assert::close(&upper.values[6..8], &[0.0, 0.0][..], 0.0);
+        let identity = Conventional::from(Diagonal::from_vec(4, vec![1.0; 4]));
+        assert::close(
+            &*orthogonal.transpose().multiply(&orthogonal),
+            &*identity,
+            1e-14,
+        );
+        assert::close(&*orthogonal.multiply(&upper), &*matrix, 1e-13);
+    }
+
+    #[test]
+    fn decompose_wide() {
+        let matrix = Conventional::from_vec(
+            (2, 3),
+            matrix![
+                1.0, 2.0, 3.0;
+                4.0, 5.0, 6.0;
+            ],
+        );
+        for &mode in &[Mode::Economy, Mode::Full] {
+            let (orthogonal, upper) = QR::decompose(&matrix, mode).unwrap();
+            assert_eq!((orthogonal.rows, orthogonal.columns), (2, 2));
+            assert_eq!((upper.rows, upper.columns), (2, 3));
+            assert_eq!(upper[(1, 0)], 0.0);
+            assert::close(&*orthogonal.multiply(&upper), &*matrix, 1e-14);
+        }
+    }
+
+    #[test]
+    fn decompose_pivoted() {
+        let matrix = Conventional::from_vec(
+            (4, 3),
+            matrix![
+                1.0f64, 2.0,  3.0;
+                1.0,    4.0,  5.0;
+                1.0,    6.0,  7.0;
+                1.0,    8.0, 10.0;
+            ],
+        );
+        let (orthogonal, upper, permutation) =
+            QR::decompose_pivoted(&matrix, Mode::Economy).unwrap();
+        assert_eq!(&permutation, &[2, 0, 1]);
+        assert!(upper[(0, 0)].abs() >= upper[(1, 1)].abs());
+        assert!(upper[(1, 1)].abs() >= upper[(2, 2)].abs());
+        let product = orthogonal.multiply(&upper);
+        for i in 0..4 {
+            for j in 0..3 {
+                assert::close(product[(i, j)], matrix[(i, permutation[j])], 1e-13);
+            }
+        }
+    }
+
+    #[test]
+    fn decompose_single() {
+        let matrix = Conventional::from_vec((2, 2), vec![3.0f32, 4.0, 0.0, 5.0]);
+        let (orthogonal, upper) = QR::decompose(&matrix, Mode::Full).unwrap();
+        assert::close(&*orthogonal, &[-0.6, -0.8, -0.8, 0.6][..], 1e-6);
+        assert::close(&*upper, &[-5.0, 0.0, -4.0, 3.0][..], 1e-6);
+    }
+}
@@ -0,0 +1,268 @@
+use std::cmp::Ordering;
+
+use num_traits::Float;
+
+use decomposition::{Mode, SingularValue};
+use format::{Conventional, Diagonal};
+use operation::Transpose;
+use {Element, Result};
+
+use super::qr;
+
+const SWEEPS: usize = 100;
+
+macro_rules! implement(
+    ($kind:ty) => (
+        impl SingularValue<$kind> for Conventional<$kind> {
+            #[inline]
+            fn decompose(
+                &self,
+            ) -> Result<(Conventional<$kind>, Diagonal<$kind>, Conventional<$kind>)> {
+                decompose(self)
+            }
+        }
+    );
+);
+
+implement!(f32);
+#[cfg(not(feature = "acceleration"))]
+implement!(f64);
+
+/// Perform the decomposition using the one-sided Jacobi method.
+///
+/// The columns of the matrix are orthogonalized by plane rotations, which are
+/// accumulated to form the right singular vectors. The left singular vectors
+/// corresponding to zero singular values, if any, are obtained by completing
+/// the rest to an orthonormal basis.
+fn decompose<T>(matrix: &Conventional<T>) -> Result<(Conventional<T>, Diagonal<T>, Conventional<T>)>
+where
+    T: Element + Float,
+{
+    let (m, n) = (matrix.rows, matrix.columns);
+    if matrix.values.iter().any(|value| !value.is_finite()) {
+        raise!("expected a matrix with finite elements");
+    }
+    if m < n {
+        let (left, values, right) = decompose(&matrix.transpose())?;
+        return Ok((
+            right.transpose(),
+            Diagonal::from_vec((m, n), values.values),
+            left.transpose(),
+        ));
+    }
+    let mut work = matrix.values.clone();
+    let mut vectors = Conventional::new(n);
+    for i in 0..n {
+        vectors.values[i * n + i] = T::one();
+    }
+    let tolerance = T::epsilon() * T::from(m).unwrap();
+    let mut converged = false;
+    for _ in 0..SWEEPS {
+        let mut rotated = false;
+        for p in 0..n {
+            for q in (p + 1)..n {
+                let (mut alpha, mut beta, mut gamma) = (
+                    <T as Element>::zero(),
+                    <T as Element>::zero(),
+                    <T as Element>::zero(),
+                );
+                for (&x, &y) in work[(p * m)..((p + 1) * m)]
+                    .iter()
+                    .zip(&work[(q * m)..((q + 1) * m)])
+                {
+                    alpha = alpha + x * x;
+                    beta = beta + y * y;
+                    gamma = gamma + x * y;
+                }
+                if gamma.abs() <= tolerance * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+                let zeta = (beta - alpha) / (gamma + gamma);
+                let tangent = T::one().copysign(zeta) / (zeta.abs() + zeta.hypot(T::one()));
+                let cosine = T::one() / tangent.hypot(T::one());
+                let sine = cosine * tangent;
+                rotate(&mut work, m, p, q, cosine, sine);
+                rotate(&mut vectors.values, n, p, q, cosine, sine);
+            }
+        }
+        if !rotated {
+            converged = true;
+            break;
+        }
+    }
+    if !converged {
+        raise!(NonConvergence {
+            iterations: Some(SWEEPS),
+        });
+    }
+    let norms = work
+        .chunks(m)
+        .map(|column| {
+            column
+                .iter()
+                .fold(<T as Element>::zero(), |sum, &value| sum.hypot(value))
+        })
+        .collect::<Vec<_>>();
+    let mut order = (0..n).collect::<Vec<_>>();
+    order.sort_by(|&i, &j| norms[j].partial_cmp(&norms[i]).unwrap_or(Ordering::Equal));
+    let rank = order
+        .iter()
+        .take_while(|&&j| !Element::is_zero(&norms[j]))
+        .count();
+    let mut left = Conventional::new(m);
+    for (k, &j) in order[..rank].iter().enumerate() {
+        let scale = norms[j].recip();
+        for (target, &value) in left.values[(k * m)..((k + 1) * m)]
+            .iter_mut()
+            .zip(&work[(j * m)..((j + 1) * m)])
+        {
+            *target = value * scale;
+        }
+    }
+    if rank < m {
+        let mut factors = left.values[..(rank * m)].to_vec();
+        let mut taus = vec![<T as Element>::zero(); rank];
+        qr::factorize(&mut factors, &mut taus, None, m, rank);
+        let (orthogonal, _) = qr::unpack(&factors, &taus, m, rank, Mode::Full);
+        left.values[(rank * m)..].copy_from_slice(&orthogonal.values[(rank * m)..]);
+    }
+    let values = order.iter().map(|&j| norms[j]).collect();
+    let mut right = Conventional::new(n);
+    for (k, &j) in order.iter().enumerate() {
+        for i in 0..n {
+            right.values[i * n + k] = vectors.values[j * n + i];
+        }
+    }
+    Ok((left, Diagonal::from_vec((m, n), values), right))
+}
+
+fn rotate<T>(matrix: &mut [T], rows: usize, p: usize, q: usize, cosine: T, sine: T)
+where
+    T: Element + Float,
+{
+    let (head, tail) = matrix.split_at_mut(q * rows);
+    let first = &mut head[(p * rows)..((p + 1) * rows)];
+    let second = &mut tail[..rows];
+    for (x, y) in first.iter_mut().zip(second) {
+        let (a, b) = (*x, *y);
+        *x = cosine * a - sine * b;
+        *y = sine * a + cosine * b;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    fn check(matrix: &Conventional<f64>, delta: f64) {
+        let (m, n) = (matrix.rows, matrix.columns);
+        let (left, values, right) = SingularValue::decompose(matrix).unwrap();
+        assert_eq!((left.rows, left.columns), (m, m));
+        assert_eq!((values.rows, values.columns), (m, n));
+        assert_eq!((right.rows, right.columns), (n, n));
+        for k in 1..values.len() {
+            assert!(values[k - 1] >= values[k]);
+        }
+        let identity = |size| Conventional::from(Diagonal::from_vec(size, vec![1.0; size]));
+        assert::close(&*left.transpose().multiply(&left), &*identity(m), delta);
+        assert::close(&*right.transpose().multiply(&right), &*identity(n), delta);
+        let product = left.multiply(&Conventional::from(&values));
+        assert::close(&*product.multiply(&right), &**matrix, delta);
+    }
+
+    #[test]
+    fn decompose_tall() {
+        let matrix = Conventional::from_vec(
+            (4, 2),
+            matrix![
+                1.0f64, 2.0;
+                3.0,    4.0;
+                5.0,    6.0;
+                7.0,    8.0;
+            ],
+        );
+        let (left, values, right) = SingularValue::decompose(&matrix).unwrap();
+        assert::close(
+            &*values,
+            &*vec![1.426909549926149e+01, 6.268282324175424e-01],
+            1e-14,
+        );
+        let expected: [f64; 8] = [
+            -1.524832333102012e-01,
+            -3.49918371807964e-01,
+            -5.473535103057272e-01,
+            -7.447886488034903e-01,
+            -8.226474722256604e-01,
+            -4.213752876845798e-01,
+            -2.010310314350211e-02,
+            3.811690813975744e-01,
+        ];
+        for j in 0..2 {
+            let sign = left[(0, j)].signum() * expected[4 * j].signum();
+            for i in 0..4 {
+                assert::close(sign * left[(i, j)], expected[4 * j + i], 1e-14);
+            }
+        }
+        let expected: [f64; 4] = [
+            -6.414230279950722e-01,
+            7.671873950721771e-01,
+            -7.671873950721771e-01,
+            -6.414230279950722e-01,
+        ];
+        for i in 0..2 {
+            let sign = right[(i, 0)].signum() * expected[i].signum();
+            for j in 0..2 {
+                assert::close(sign * right[(i, j)], expected[2 * j + i], 1e-14);
+            }
+        }
+        check(&matrix, 1e-13);
+    }
+
+    #[test]
+    fn decompose_wide() {
+        let matrix = Conventional::from_vec(
+            (2, 4),
+            matrix![
+                1.0, 3.0, 5.0, 7.0;
+                2.0, 4.0, 6.0, 8.0;
+            ],
+        );
+        let (_, values, _) = SingularValue::decompose(&matrix).unwrap();
+        assert::close(
+            &*values,
+            &*vec![1.426909549926149e+01, 6.268282324175424e-01],
+            1e-14,
+        );
+        check(&matrix, 1e-13);
+    }
+
+    #[test]
+    fn decompose_rank_deficient() {
+        let matrix = Conventional::from_vec(
+            (3, 3),
+            matrix![
+                1.0, 2.0, 3.0;
+                2.0, 4.0, 6.0;
+                0.0, 0.0, 0.0;
+            ],
+        );
+        let (_, values, _) = SingularValue::decompose(&matrix).unwrap();
+        assert::close(&*values, &*vec![70f64.sqrt(), 0.0, 0.0], 1e-14);
+        check(&matrix, 1e-14);
+    }
+
+    #[test]
+    fn decompose_nonfinite() {
+        let matrix = Conventional::from_vec(2, vec![1.0f32, f32::NAN, 0.0, 1.0]);
+        assert!(SingularValue::decompose(&matrix).is_err());
+    }
+
+    #[test]
+    fn decompose_single() {
+        let matrix = Conventional::from_vec((2, 2), vec![3.0f32, 0.0, 0.0, -4.0]);
+        let (_, values, _) = SingularValue::decompose(&matrix).unwrap();
+        assert_eq!(&*values, &[4.0, 3.0]);
+    }
+}
@@ -0,0 +1,319 @@
+use num_traits::Float;
+
+use decomposition::SymmetricEigen;
+use format::{Conventional, Diagonal};
+use {Element, Result};
+
+const ITERATIONS: usize = 30;
+
+macro_rules! implement(
+    ($kind:ty) => (
+        impl SymmetricEigen<$kind> for Conventional<$kind> {
+            #[inline]
+            fn decompose(&self) -> Result<(Conventional<$kind>, Diagonal<$kind>)> {
+                decompose(self)
+            }
+        }
+    );
+);
+
+implement!(f32);
+#[cfg(not(feature = "acceleration"))]
+implement!(f64);
+
+/// Perform the decomposition.
+///
+/// The matrix, of which only the upper triangle is referenced, is reduced to
+/// a tridiagonal form by Householder transformations, and the tridiagonal
+/// matrix is diagonalized by the QL algorithm with implicit shifts. The
+/// eigenvalues are returned in the ascending order.
+fn decompose<T>(matrix: &Conventional<T>) -> Result<(Conventional<T>, Diagonal<T>)>
+where
+    T: Element + Float,
+{
+    let n = square!(matrix);
+    let mut vectors = matrix.clone();
+    for j in 0..n {
+        for i in (j + 1)..n {
+            vectors[(i, j)] = vectors[(j, i)];
+        }
+    }
+    let mut values = vec![<T as Element>::zero(); n];
+    let mut off = vec![<T as Element>::zero(); n];
+    if n > 0 {
+        tridiagonalize(&mut vectors, &mut values, &mut off);
+        diagonalize(&mut vectors, &mut values, &mut off)?;
+    }
+    Ok((vectors, Diagonal::from_vec(n, values)))
+}
+
+/// Reduce a symmetric matrix to a tridiagonal form.
+///
+/// The matrix is overwritten with the accumulated transformation, and the
+/// diagonal and subdiagonal of the tridiagonal matrix are stored in `d` and
+/// `e[1..]`, respectively.
+fn tridiagonalize<T>(v: &mut Conventional<T>, d: &mut [T], e: &mut [T])
+where
+    T: Element + Float,
+{
+    let n = v.rows;
+    let zero = <T as Element>::zero();
+    for j in 0..n {
+        d[j] = v[(n - 1, j)];
+    }
+    for i in (1..n).rev() {
+        let scale = d[..i].iter().fold(zero, |sum, &value| sum + value.abs());
+        let mut h = zero;
+        if Element::is_zero(&scale) {
+            e[i] = d[i - 1];
+            for j in 0..i {
+                d[j] = v[(i - 1, j)];
+                v[(i, j)] = zero;
+                v[(j, i)] = zero;
+            }
+        } else {
+            for value in &mut d[..i] {
+                *value = *value / scale;
+                h = h + *value * *value;
+            }
+            let mut f = d[i - 1];
+            let mut g = h.sqrt();
+            if f > zero {
+                g = -g;
+            }
+            e[i] = scale * g;
+            h = h - f * g;
+            d[i - 1] = f - g;
+            for value in &mut e[..i] {
+                *value = zero;
+            }
+            for j in 0..i {
+                f = d[j];
+                v[(j, i)] = f;
+                g = e[j] + v[(j, j)] * f;
+                for k in (j + 1)..i {
+                    g = g + v[(k, j)] * d[k];
+                    e[k] = e[k] + v[(k, j)] * f;
+                }
+                e[j] = g;
+            }
+            f = zero;
+            for j in 0..i {
+                e[j] = e[j] / h;
+                f = f + e[j] * d[j];
+            }
+            let hh = f / (h + h);
+            for j in 0..i {
+                e[j] = e[j] - hh * d[j];
+            }
+            for j in 0..i {
+                f = d[j];
+                g = e[j];
+                for k in j..i {
+                    v[(k, j)] = v[(k, j)] - (f * e[k] + g * d[k]);
+                }
+                d[j] = v[(i - 1, j)];
+                v[(i, j)] = zero;
+            }
+        }
+        d[i] = h;
+    }
+    for i in 0..(n - 1) {
+        v[(n - 1, i)] = v[(i, i)];
+        v[(i, i)] = T::one();
+        let h = d[i + 1];
+        if !Element::is_zero(&h) {
+            for k in 0..(i + 1) {
+                d[k] = v[(k, i + 1)] / h;
+            }
+            for j in 0..(i + 1) {
+                let mut g = zero;
+                for k in 0..(i + 1) {
+                    g = g + v[(k, i + 1)] * v[(k, j)];
+                }
+                for k in 0..(i + 1) {
+                    v[(k, j)] = v[(k, j)] - g * d[k];
+                }
+            }
+        }
+        for k in 0..(i + 1) {
+            v[(k, i + 1)] = zero;
+        }
+    }
+    for j in 0..n {
+        d[j] = v[(n - 1, j)];
+        v[(n - 1, j)] = zero;
+    }
+    v[(n - 1, n - 1)] = T::one();
+    e[0] = zero;
+}
+
+/// Diagonalize a symmetric tridiagonal matrix.
+///
+/// The eigenvalues are written to `d` in the ascending order, and the
+/// transformation is accumulated in the matrix.
+fn diagonalize<T>(v: &mut Conventional<T>, d: &mut [T], e: &mut [T]) -> Result<()>
+where
+    T: Element + Float,
+{
+    let n = v.rows;
+    let zero = <T as Element>::zero();
+    let two = T::one() + T::one();
+    for i in 1..n {
+        e[i - 1] = e[i];
+    }
+    e[n - 1] = zero;
+    let mut f = zero;
+    let mut tst1 = zero;
+    let eps = T::epsilon();
+    for l in 0..n {
+        tst1 = tst1.max(d[l].abs() + e[l].abs());
+        let mut m = l;
+        while m < n {
+            if e[m].abs() <= eps * tst1 {
+                break;
+            }
+            m += 1;
+        }
+        if m > l {
+            let mut iterations = 0;
+            loop {
+                iterations += 1;
+                if iterations > ITERATIONS * n {
+                    raise!(NonConvergence {
+                        iterations: Some(ITERATIONS * n),
+                    });
+                }
+                let mut g = d[l];
+                let mut p = (d[l + 1] - g) / (two * e[l]);
+                let mut r = p.hypot(T::one());
+                if p < zero {
+                    r = -r;
+                }
+                d[l] = e[l] / (p + r);
+                d[l + 1] = e[l] * (p + r);
+                let dl1 = d[l + 1];
+                let mut h = g - d[l];
+                for value in &mut d[(l + 2)..] {
+                    *value = *value - h;
+                }
+                f = f + h;
+                p = d[m];
+                let mut c = T::one();
+                let mut c2 = c;
+                let mut c3 = c;
+                let el1 = e[l + 1];
+                let mut s = zero;
+                let mut s2 = zero;
+                for i in (l..m).rev() {
+                    c3 = c2;
+                    c2 = c;
+                    s2 = s;
+                    g = c * e[i];
+                    h = c * p;
+                    r = p.hypot(e[i]);
+                    e[i + 1] = s * r;
+                    s = e[i] / r;
+                    c = p / r;
+                    p = c * d[i] - s * g;
+                    d[i + 1] = h + s * (c * g + s * d[i]);
+                    for k in 0..n {
+                        h = v[(k, i + 1)];
+                        v[(k, i + 1)] = s * v[(k, i)] + c * h;
+                        v[(k, i)] = c * v[(k, i)] - s * h;
+                    }
+                }
+                p = -s * s2 * c3 * el1 * e[l] / dl1;
+                e[l] = s * p;
+                d[l] = c * p;
+                if e[l].abs() <= eps * tst1 {
+                    break;
+                }
+            }
+        }
+        d[l] = d[l] + f;
+        e[l] = zero;
+    }
+    for i in 0..n {
+        let mut k = i;
+        for j in (i + 1)..n {
+            if d[j] < d[k] {
+                k = j;
+            }
+        }
+        if k != i {
+            d.swap(i, k);
+            for j in 0..n {
+                let (first, second) = (v[(j, i)], v[(j, k)]);
+                v[(j, i)] = second;
+                v[(j, k)] = first;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    #[test]
+    fn decompose() {
+        let matrix = Conventional::from_vec(
+            4,
+            matrix![
+                1.0f64, 1.0 / 2.0, 1.0 / 3.0, 1.0 / 4.0;
+                0.0,    1.0,       2.0 / 3.0, 1.0 / 2.0;
+                0.0, 0.0, 1.0, 3.0 / 4.0;
+                0.0, 0.0, 0.0, 1.0;
+            ],
+        );
+        let (vectors, values) = SymmetricEigen::decompose(&matrix).unwrap();
+        let expected: [f64; 16] = [
+            6.93185260742776e-02,
+            -3.617963298359111e-01,
+            7.693670370857654e-01,
+            -5.218933989868291e-01,
+            -4.42222850107573e-01,
+            7.420398064553687e-01,
+            4.863601702209238e-02,
+            -5.014483167053618e-01,
+            -8.104763801066263e-01,
+            -1.877143925990472e-01,
+            3.009681045547824e-01,
+            4.661647178209991e-01,
+            3.778384973436192e-01,
+            5.322063962074435e-01,
+            5.613618263961305e-01,
+            5.087900565323598e-01,
+        ];
+        for j in 0..4 {
+            let sign = vectors[(0, j)].signum() * expected[4 * j].signum();
+            for i in 0..4 {
+                assert::close(sign * vectors[(i, j)], expected[4 * j + i], 1e-14);
+            }
+        }
+        assert::close(
+            &*values,
+            &*vec![
+                2.07775485918012e-01,
+                4.078328841178751e-01,
+                8.482291554779129e-01,
+                2.536162474486201e+00,
+            ],
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn decompose_diagonal() {
+        let matrix = Conventional::from(Diagonal::from_vec(3, vec![3.0f32, -1.0, 2.0]));
+        let (vectors, values) = SymmetricEigen::decompose(&matrix).unwrap();
+        assert_eq!(&*values, &[-1.0, 2.0, 3.0]);
+        assert_eq!(
+            &*vectors.iter().map(|value| value.abs()).collect::<Vec<_>>(),
+            &[0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0]
+        );
+    }
+}
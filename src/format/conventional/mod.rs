@@ -6,7 +6,7 @@ use std::ops::{Deref, DerefMut, Index, IndexMut};
 use std::ptr;
 use std::fmt;
 
-use {Element, Matrix, Position, Size};
+use {Element, Matrix, Position, Result, Size};
 
 /// A conventional matrix.
 #[derive(Clone, Debug, PartialEq)]
@@ -36,6 +36,18 @@ mod operation;
 size!(Conventional);
 
 impl<T: Element> Conventional<T> {
+    /// Check that the matrix is valid.
+    pub fn validate(&self) -> Result<()> {
+        if self.values.len() != self.rows * self.columns {
+            raise!(
+                "expected {} values but got {}",
+                self.rows * self.columns,
+                self.values.len()
+            );
+        }
+        Ok(())
+    }
+
     /// Create a zero matrix.
     pub fn new<S: Size>(size: S) -> Self {
         let (rows, columns) = size.dimensions();
@@ -56,6 +68,20 @@ impl<T: Element> Conventional<T> {
         new!(rows, columns, values)
     }
 
+    /// Create a matrix from a slice checking its length.
+    #[inline]
+    pub fn try_from_slice<S: Size>(size: S, values: &[T]) -> Result<Self> {
+        Conventional::try_from_vec(size, values.to_vec())
+    }
+
+    /// Create a matrix from a vector checking its length.
+    pub fn try_from_vec<S: Size>(size: S, values: Vec<T>) -> Result<Self> {
+        let (rows, columns) = size.dimensions();
+        let matrix = new!(rows, columns, values);
+        matrix.validate()?;
+        Ok(matrix)
+    }
+
     /// Create a matrix with uninitialized elements.
     pub unsafe fn with_uninitialized<S: Size>(size: S) -> Self {
         let (rows, columns) = size.dimensions();
@@ -262,4 +288,11 @@ mod tests {
         let matrix = Conventional::from_vec(2, vec![1.0, 2.0, 3.0, 0.0]);
         assert_eq!(matrix.nonzeros(), 3);
     }
+
+    #[test]
+    fn try_from_vec() {
+        assert!(Conventional::try_from_vec((2, 3), vec![1.0; 6]).is_ok());
+        assert!(Conventional::try_from_vec((2, 3), vec![1.0; 5]).is_err());
+        assert!(Conventional::try_from_slice(2, &[1.0; 5]).is_err());
+    }
 }
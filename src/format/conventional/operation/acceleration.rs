@@ -3,21 +3,57 @@ use blas as backend;
 use format::Conventional;
 use operation::{Multiply, MultiplyInto, ScaleSelf};
 
+use super::multiplicand;
+
 impl Multiply<[f64], Conventional<f64>> for Conventional<f64> {
     #[inline]
     fn multiply(&self, right: &[f64]) -> Self {
         let (m, p) = (self.rows, self.columns);
-        let n = right.len() / p;
+        let n = multiplicand!(m, p, right);
         let mut result = unsafe { Conventional::with_uninitialized((m, n)) };
         multiply(1.0, &self.values, right, 0.0, &mut result.values, m, p, n);
         result
     }
 }
 
-impl MultiplyInto<Conventional<f64>, [f64]> for Conventional<f64> {
+impl Multiply<Conventional<f64>, Conventional<f64>> for Conventional<f64> {
+    #[inline]
+    fn multiply(&self, right: &Self) -> Self {
+        let (m, p, n) = (self.rows, self.columns, multiplicand(self, right));
+        let mut result = unsafe { Conventional::with_uninitialized((m, n)) };
+        multiply(
+            1.0,
+            &self.values,
+            &right.values,
+            0.0,
+            &mut result.values,
+            m,
+            p,
+            n,
+        );
+        result
+    }
+}
+
+impl Multiply<Vec<f64>, Conventional<f64>> for Conventional<f64> {
     #[inline(always)]
+    fn multiply(&self, right: &Vec<f64>) -> Self {
+        Multiply::multiply(self, &right[..])
+    }
+}
+
+impl MultiplyInto<Conventional<f64>, [f64]> for Conventional<f64> {
+    #[inline]
     fn multiply_into(&self, right: &Self, result: &mut [f64]) {
-        MultiplyInto::multiply_into(self, &*right as &[f64], result)
+        let (m, p, n) = (self.rows, self.columns, multiplicand(self, right));
+        if result.len() != m * n {
+            panic!(
+                "expected a result of {} elements but got {}",
+                m * n,
+                result.len()
+            );
+        }
+        multiply(1.0, &self.values, &right.values, 1.0, result, m, p, n)
     }
 }
 
@@ -32,7 +68,7 @@ impl MultiplyInto<[f64], [f64]> for Conventional<f64> {
     #[inline]
     fn multiply_into(&self, right: &[f64], result: &mut [f64]) {
         let (m, p) = (self.rows, self.columns);
-        let n = right.len() / p;
+        let n = multiplicand!(m, p, right, result);
         multiply(1.0, &self.values, right, 1.0, result, m, p, n)
     }
 }
@@ -59,6 +95,15 @@ fn multiply(
     debug_assert_eq!(a.len(), m * p);
     debug_assert_eq!(b.len(), p * n);
     debug_assert_eq!(c.len(), m * n);
+    if m == 0 || n == 0 {
+        return;
+    }
+    if p == 0 {
+        for value in c.iter_mut() {
+            *value *= beta;
+        }
+        return;
+    }
     let (m, p, n) = (m as i32, p as i32, n as i32);
     if n == 1 {
         unsafe {
@@ -114,6 +159,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn multiply_empty_inside() {
+        let matrix = Conventional::from_vec((2, 0), vec![]);
+        let right = Conventional::from_vec((0, 3), vec![]);
+        assert_eq!(matrix.multiply(&right), Conventional::new((2, 3)));
+        let mut result = Conventional::from_vec((2, 3), vec![1.0; 6]);
+        matrix.multiply_into(&right, &mut result);
+        assert_eq!(result, Conventional::from_vec((2, 3), vec![1.0; 6]));
+    }
+
     #[test]
     fn scale_self() {
         let mut matrix = Conventional::from_vec(2, vec![21.0, 21.0, 21.0, 21.0]);
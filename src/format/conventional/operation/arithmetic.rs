@@ -0,0 +1,127 @@
+use std::ops::{Add, Sub};
+
+use format::{Banded, Compressed, Conventional, Diagonal, Packed};
+use {Element, Number};
+
+macro_rules! implement(
+    ($operator:ident, $function:ident, $verb:expr) => (
+        impl<'l, 'r, T> $operator<&'r Conventional<T>> for &'l Conventional<T>
+        where
+            T: Element + Number,
+        {
+            type Output = Conventional<T>;
+
+            #[inline]
+            fn $function(self, right: &'r Conventional<T>) -> Self::Output {
+                combine(self.clone(), right, <T as $operator>::$function, $verb)
+            }
+        }
+
+        forward!($operator, $function, Conventional, Conventional, Conventional);
+
+        implement!($operator, $function, $verb, Banded);
+        implement!($operator, $function, $verb, Compressed);
+        implement!($operator, $function, $verb, Diagonal);
+        implement!($operator, $function, $verb, Packed);
+    );
+    ($operator:ident, $function:ident, $verb:expr, $kind:ident) => (
+        impl<'l, 'r, T> $operator<&'r $kind<T>> for &'l Conventional<T>
+        where
+            T: Element + Number,
+        {
+            type Output = Conventional<T>;
+
+            #[inline]
+            fn $function(self, right: &'r $kind<T>) -> Self::Output {
+                conform!(self, right, $verb);
+                let right = Conventional::from(right);
+                combine(self.clone(), &right, <T as $operator>::$function, $verb)
+            }
+        }
+
+        impl<'l, 'r, T> $operator<&'r Conventional<T>> for &'l $kind<T>
+        where
+            T: Element + Number,
+        {
+            type Output = Conventional<T>;
+
+            #[inline]
+            fn $function(self, right: &'r Conventional<T>) -> Self::Output {
+                conform!(self, right, $verb);
+                combine(Conventional::from(self), right, <T as $operator>::$function, $verb)
+            }
+        }
+
+        forward!($operator, $function, Conventional, $kind, Conventional);
+        forward!($operator, $function, $kind, Conventional, Conventional);
+    );
+);
+
+implement!(Add, add, "add");
+implement!(Sub, sub, "subtract");
+
+scalar!(Conventional);
+
+fn combine<T, F>(
+    mut left: Conventional<T>,
+    right: &Conventional<T>,
+    operation: F,
+    verb: &str,
+) -> Conventional<T>
+where
+    T: Element,
+    F: Fn(T, T) -> T,
+{
+    conform!(&left, right, verb);
+    for (value, &other) in left.values.iter_mut().zip(&right.values) {
+        *value = operation(*value, other);
+    }
+    left
+}
+
+#[cfg(test)]
+mod tests {
+    use format::packed::Variant;
+    use prelude::*;
+
+    #[test]
+    fn add() {
+        let left = Conventional::from_vec(2, vec![1.0, 2.0, 3.0, 4.0]);
+        let right = Conventional::from_vec(2, vec![4.0, 3.0, 2.0, 1.0]);
+        assert_eq!(&*(&left + &right), &[5.0, 5.0, 5.0, 5.0]);
+        assert_eq!(&*(left - right), &[-3.0, -1.0, 1.0, 3.0]);
+    }
+
+    #[test]
+    fn add_mixed() {
+        let matrix = Conventional::from_vec(2, vec![1.0, 2.0, 3.0, 4.0]);
+        let diagonal = Diagonal::from_vec(2, vec![10.0, 20.0]);
+        assert_eq!(&*(&matrix + &diagonal), &[11.0, 2.0, 3.0, 24.0]);
+        assert_eq!(&*(&diagonal - &matrix), &[9.0, -2.0, -3.0, 16.0]);
+        let packed = Packed {
+            size: 2,
+            variant: Variant::Lower,
+            values: vec![1.0, 1.0, 1.0],
+        };
+        assert_eq!(&*(&matrix - &packed), &[0.0, 1.0, 3.0, 3.0]);
+        let compressed = Compressed::from(&matrix);
+        assert_eq!(&*(&matrix + compressed), &[2.0, 4.0, 6.0, 8.0]);
+        let banded = Banded::from(&diagonal);
+        assert_eq!(&*(banded + matrix), &[11.0, 2.0, 3.0, 24.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot add a 2 × 2 matrix and a 2 × 3 matrix")]
+    fn add_mismatch() {
+        let left = Conventional::<f64>::new(2);
+        let right = Conventional::new((2, 3));
+        let _ = left + right;
+    }
+
+    #[test]
+    fn scale() {
+        let matrix = Conventional::from_vec(2, vec![1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(&*(&matrix * 2.0), &[2.0, 4.0, 6.0, 8.0]);
+        assert_eq!(&*(-matrix), &[-1.0, -2.0, -3.0, -4.0]);
+    }
+}
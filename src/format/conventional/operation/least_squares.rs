@@ -0,0 +1,255 @@
+use num_traits::Float;
+
+use format::conventional::decomposition::qr;
+use format::Conventional;
+use operation::LeastSquares;
+use {Element, Result};
+
+impl<T> LeastSquares<T, [T], Vec<T>> for Conventional<T>
+where
+    T: Element + Float,
+{
+    fn solve_least_squares(&self, right: &[T]) -> Result<(Vec<T>, Vec<T>, usize)> {
+        if right.len() != self.rows {
+            raise!(DimensionMismatch {
+                left: (self.rows, self.columns),
+                right: (right.len(), 1),
+            });
+        }
+        solve(self, right, 1)
+    }
+}
+
+impl<T> LeastSquares<T, Vec<T>, Vec<T>> for Conventional<T>
+where
+    T: Element + Float,
+{
+    #[inline(always)]
+    fn solve_least_squares(&self, right: &Vec<T>) -> Result<(Vec<T>, Vec<T>, usize)> {
+        LeastSquares::solve_least_squares(self, &right[..])
+    }
+}
+
+impl<T> LeastSquares<T, Conventional<T>, Conventional<T>> for Conventional<T>
+where
+    T: Element + Float,
+{
+    fn solve_least_squares(&self, right: &Self) -> Result<(Self, Vec<T>, usize)> {
+        if right.rows != self.rows {
+            raise!(DimensionMismatch {
+                left: (self.rows, self.columns),
+                right: (right.rows, right.columns),
+            });
+        }
+        let (values, residuals, rank) = solve(self, &right.values, right.columns)?;
+        Ok((
+            Conventional::from_vec((self.columns, right.columns), values),
+            residuals,
+            rank,
+        ))
+    }
+}
+
+/// Solve via the complete orthogonal decomposition.
+///
+/// The matrix is factorized as `A × P = Q × R` using the QR decomposition with
+/// column pivoting, and the effective rank `r` is determined from the diagonal
+/// of `R`. If `r` is less than the number of columns, the first `r` rows of
+/// `R` are further factorized as `Tᵀ × Zᵀ` so that the minimum-norm solution
+/// can be obtained.
+fn solve<T>(
+    matrix: &Conventional<T>,
+    values: &[T],
+    columns: usize,
+) -> Result<(Vec<T>, Vec<T>, usize)>
+where
+    T: Element + Float,
+{
+    let (m, n) = (matrix.rows, matrix.columns);
+    let k = min!(m, n);
+    if k == 0 {
+        let residuals = (0..columns)
+            .map(|j| norm(&values[(j * m)..((j + 1) * m)]))
+            .collect();
+        return Ok((vec![<T as Element>::zero(); n * columns], residuals, 0));
+    }
+    let mut factors = matrix.values.clone();
+    let mut taus = vec![<T as Element>::zero(); k];
+    let mut permutation = (0..n).collect::<Vec<_>>();
+    qr::factorize(&mut factors, &mut taus, Some(&mut permutation), m, n);
+    let rank = rank(&factors, m, n);
+    let mut inner = Vec::new();
+    let mut inner_taus = vec![<T as Element>::zero(); rank];
+    if rank < n {
+        inner = vec![<T as Element>::zero(); n * rank];
+        for i in 0..rank {
+            for j in i..n {
+                inner[i * n + j] = factors[j * m + i];
+            }
+        }
+        qr::factorize(&mut inner, &mut inner_taus, None, n, rank);
+    }
+    let mut solution = vec![<T as Element>::zero(); n * columns];
+    for (right, solution) in values.chunks(m).zip(solution.chunks_mut(n)) {
+        let mut right = right.to_vec();
+        for l in 0..k {
+            qr::apply(&factors[(l * m + l)..((l + 1) * m)], taus[l], &mut right[l..]);
+        }
+        let mut result = vec![<T as Element>::zero(); n];
+        if rank == n {
+            for i in (0..n).rev() {
+                let mut sum = right[i];
+                for j in (i + 1)..n {
+                    sum = sum - factors[j * m + i] * result[j];
+                }
+                result[i] = sum / factors[i * m + i];
+            }
+        } else {
+            for i in 0..rank {
+                let mut sum = right[i];
+                for j in 0..i {
+                    sum = sum - inner[i * n + j] * result[j];
+                }
+                result[i] = sum / inner[i * n + i];
+            }
+            for l in (0..rank).rev() {
+                qr::apply(&inner[(l * n + l)..((l + 1) * n)], inner_taus[l], &mut result[l..]);
+            }
+        }
+        for (&value, &j) in result.iter().zip(&permutation) {
+            solution[j] = value;
+        }
+    }
+    let mut residuals = Vec::with_capacity(columns);
+    for (right, solution) in values.chunks(m).zip(solution.chunks(n)) {
+        let mut residual = right.to_vec();
+        for (column, &value) in matrix.values.chunks(m).zip(solution) {
+            for (residual, &element) in residual.iter_mut().zip(column) {
+                *residual = *residual - element * value;
+            }
+        }
+        residuals.push(norm(&residual));
+    }
+    Ok((solution, residuals, rank))
+}
+
+fn norm<T>(values: &[T]) -> T
+where
+    T: Element + Float,
+{
+    values
+        .iter()
+        .fold(<T as Element>::zero(), |sum, &value| sum.hypot(value))
+}
+
+/// Compute the effective rank of a matrix factorized with column pivoting.
+pub fn rank<T>(factors: &[T], m: usize, n: usize) -> usize
+where
+    T: Element + Float,
+{
+    let k = min!(m, n);
+    if k == 0 {
+        return 0;
+    }
+    let size = T::from(if m > n { m } else { n }).unwrap();
+    let tolerance = size * T::epsilon() * factors[0].abs();
+    (0..k)
+        .take_while(|&i| factors[i * m + i].abs() > tolerance)
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    #[test]
+    fn solve_overdetermined() {
+        let matrix = Conventional::from_vec(
+            (4, 2),
+            matrix![
+                1.0, 0.0;
+                1.0, 1.0;
+                1.0, 2.0;
+                1.0, 3.0;
+            ],
+        );
+        let (solution, residuals, rank) = matrix
+            .solve_least_squares(&vec![1.0, 3.0, 4.0, 7.0])
+            .unwrap();
+        assert::close(&solution, &[0.9, 1.9][..], 1e-14);
+        assert::close(&residuals, &[0.7f64.sqrt()][..], 1e-14);
+        assert_eq!(rank, 2);
+    }
+
+    #[test]
+    fn solve_underdetermined() {
+        let matrix = Conventional::from_vec((1, 2), vec![1.0, 1.0]);
+        let (solution, residuals, rank) = matrix.solve_least_squares(&vec![2.0]).unwrap();
+        assert::close(&solution, &[1.0, 1.0][..], 1e-14);
+        assert::close(&residuals, &[0.0][..], 1e-14);
+        assert_eq!(rank, 1);
+    }
+
+    #[test]
+    fn solve_rank_deficient() {
+        let matrix = Conventional::from_vec(
+            (3, 2),
+            matrix![
+                1.0, 1.0;
+                1.0, 1.0;
+                1.0, 1.0;
+            ],
+        );
+        let right = Conventional::from_vec(
+            (3, 2),
+            matrix![
+                1.0, 2.0;
+                2.0, 2.0;
+                3.0, 2.0;
+            ],
+        );
+        let (solution, residuals, rank) = matrix.solve_least_squares(&right).unwrap();
+        assert_eq!((solution.rows, solution.columns), (2, 2));
+        assert::close(&*solution, &[1.0, 1.0, 1.0, 1.0][..], 1e-14);
+        assert::close(&residuals, &[2f64.sqrt(), 0.0][..], 1e-14);
+        assert_eq!(rank, 1);
+    }
+
+    #[test]
+    fn solve_zero() {
+        let matrix = Conventional::new((2, 2));
+        let (solution, residuals, rank) = matrix.solve_least_squares(&vec![3.0, 4.0]).unwrap();
+        assert_eq!(&solution, &[0.0, 0.0]);
+        assert_eq!(&residuals, &[5.0]);
+        assert_eq!(rank, 0);
+    }
+
+    #[test]
+    fn solve_empty() {
+        let matrix = Conventional::<f64>::from_vec((0, 3), vec![]);
+        let (solution, residuals, rank) = matrix.solve_least_squares(&vec![]).unwrap();
+        assert_eq!(&solution, &[0.0, 0.0, 0.0]);
+        assert_eq!(&residuals, &[0.0]);
+        assert_eq!(rank, 0);
+
+        let matrix = Conventional::from_vec((2, 0), vec![]);
+        let right = Conventional::from_vec((2, 2), vec![3.0, 4.0, 0.0, 0.0]);
+        let (solution, residuals, rank) = matrix.solve_least_squares(&right).unwrap();
+        assert_eq!((solution.rows, solution.columns), (0, 2));
+        assert_eq!(&residuals, &[5.0, 0.0]);
+        assert_eq!(rank, 0);
+
+        let matrix = Conventional::<f64>::from_vec((0, 2), vec![]);
+        let right = Conventional::from_vec((0, 3), vec![]);
+        let (solution, residuals, _) = matrix.solve_least_squares(&right).unwrap();
+        assert_eq!(solution, Conventional::new((2, 3)));
+        assert_eq!(&residuals, &[0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn solve_mismatch() {
+        let matrix = Conventional::from_vec((2, 2), vec![1.0, 2.0, 3.0, 4.0]);
+        assert!(matrix.solve_least_squares(&vec![1.0]).is_err());
+    }
+}
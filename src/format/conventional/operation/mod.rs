@@ -1,10 +1,61 @@
+use num_traits::Float;
+
+use format::conventional::decomposition::{lu, qr};
 use format::{Conventional, Diagonal};
-use operation::{MultiplySelf, Transpose};
-use {Element, Number};
+use operation::{Determinant, Inverse, MultiplySelf, Rank, Transpose};
+use {Element, Number, Result};
 
 #[cfg(feature = "acceleration")]
 mod acceleration;
 
+mod arithmetic;
+mod least_squares;
+
+#[cfg(not(feature = "acceleration"))]
+mod multiply;
+
+mod solve;
+
+impl<T> Determinant<T> for Conventional<T>
+where
+    T: Element + Float,
+{
+    fn determinant(&self) -> Result<T> {
+        let n = square!(self);
+        let mut factors = self.values.clone();
+        let mut pivots = vec![0; n];
+        if lu::factorize(&mut factors, &mut pivots, n, n).is_err() {
+            return Ok(<T as Element>::zero());
+        }
+        let mut determinant = T::one();
+        for (k, &p) in pivots.iter().enumerate() {
+            determinant = determinant * factors[k * n + k];
+            if p != k {
+                determinant = -determinant;
+            }
+        }
+        Ok(determinant)
+    }
+}
+
+impl<T> Inverse for Conventional<T>
+where
+    T: Element + Float,
+{
+    fn inverse(&self) -> Result<Self> {
+        let n = square!(self);
+        let mut factors = self.values.clone();
+        let mut pivots = vec![0; n];
+        lu::factorize(&mut factors, &mut pivots, n, n)?;
+        let mut matrix = Conventional::new(n);
+        for i in 0..n {
+            matrix.values[i * n + i] = T::one();
+        }
+        lu::substitute(&factors, &pivots, &mut matrix.values, n, n);
+        Ok(matrix)
+    }
+}
+
 impl<T> MultiplySelf<Diagonal<T>> for Conventional<T>
 where
     T: Element + Number,
@@ -12,7 +63,12 @@ where
     #[inline]
     fn multiply_self(&mut self, right: &Diagonal<T>) {
         let (rows, insides, columns) = (self.rows, self.columns, right.columns);
-        debug_assert_eq!(insides, right.rows);
+        if insides != right.rows {
+            panic!(
+                "cannot multiply a {} × {} matrix and a {} × {} matrix",
+                rows, insides, right.rows, columns
+            );
+        }
         self.resize((rows, columns));
         for j in 0..insides {
             let factor = right[j];
@@ -23,6 +79,22 @@ where
     }
 }
 
+impl<T> Rank<T> for Conventional<T>
+where
+    T: Element + Float,
+{
+    fn rank(&self, tolerance: T) -> usize {
+        let (m, n) = (self.rows, self.columns);
+        let mut factors = self.values.clone();
+        let mut taus = vec![<T as Element>::zero(); min!(m, n)];
+        let mut permutation = (0..n).collect::<Vec<_>>();
+        qr::factorize(&mut factors, &mut taus, Some(&mut permutation), m, n);
+        (0..min!(m, n))
+            .take_while(|&i| factors[i * m + i].abs() > tolerance)
+            .count()
+    }
+}
+
 impl<T: Element> Transpose for Conventional<T> {
     fn transpose(&self) -> Self {
         let (rows, columns) = (self.rows, self.columns);
@@ -36,10 +108,79 @@ impl<T: Element> Transpose for Conventional<T> {
     }
 }
 
+/// Check that two matrices can be multiplied and return the number of columns
+/// of the product.
+fn multiplicand<T: Element>(left: &Conventional<T>, right: &Conventional<T>) -> usize {
+    if left.columns != right.rows {
+        panic!(
+            "cannot multiply a {} × {} matrix and a {} × {} matrix",
+            left.rows, left.columns, right.rows, right.columns
+        );
+    }
+    right.columns
+}
+
 #[cfg(test)]
 mod tests {
+    use assert;
     use prelude::*;
 
+    #[test]
+    fn determinant() {
+        let matrix = Conventional::from_vec(
+            3,
+            matrix![
+                1.0, 2.0,  3.0;
+                4.0, 5.0,  6.0;
+                7.0, 8.0, 10.0;
+            ],
+        );
+        assert::close(matrix.determinant().unwrap(), -3.0, 1e-14);
+        let matrix = Conventional::from_vec(2, vec![1.0, 2.0, 2.0, 4.0]);
+        assert_eq!(matrix.determinant().unwrap(), 0.0);
+        let matrix = Conventional::from_vec((1, 2), vec![1.0, 2.0]);
+        assert!(matrix.determinant().is_err());
+    }
+
+    #[test]
+    fn inverse() {
+        let matrix = Conventional::from_vec(
+            3,
+            matrix![
+                1.0, 2.0,  3.0;
+                4.0, 5.0,  6.0;
+                7.0, 8.0, 10.0;
+            ],
+        );
+        assert::close(
+            &*matrix.inverse().unwrap(),
+            &*matrix![
+                -2.0 / 3.0, -4.0 / 3.0,  1.0;
+                -2.0 / 3.0, 11.0 / 3.0, -2.0;
+                 1.0,       -2.0,        1.0;
+            ],
+            1e-14,
+        );
+        let matrix = Conventional::from_vec(2, vec![1.0, 2.0, 2.0, 4.0]);
+        assert!(matrix.inverse().is_err());
+    }
+
+    #[test]
+    fn rank() {
+        let matrix = Conventional::from_vec(
+            (4, 3),
+            matrix![
+                1.0, 2.0, 3.0;
+                2.0, 4.0, 6.0;
+                1.0, 0.0, 1.0;
+                0.0, 1.0, 1.0;
+            ],
+        );
+        assert_eq!(matrix.rank(1e-10), 2);
+        assert_eq!(Conventional::<f64>::new((2, 3)).rank(0.0), 0);
+        assert_eq!(matrix.transpose().rank(1e-10), 2);
+    }
+
     #[test]
     fn multiply_self() {
         let mut matrix = Conventional::from_vec(
@@ -0,0 +1,257 @@
+use format::Conventional;
+use operation::{Multiply, MultiplyInto};
+use {Element, Number};
+
+use super::multiplicand;
+
+const BLOCK_ROWS: usize = 64;
+const BLOCK_INSIDES: usize = 256;
+
+impl<T> Multiply<[T], Conventional<T>> for Conventional<T>
+where
+    T: Element + Number,
+{
+    #[inline]
+    fn multiply(&self, right: &[T]) -> Self {
+        let (m, p) = (self.rows, self.columns);
+        let n = multiplicand!(m, p, right);
+        let mut result = Conventional::new((m, n));
+        multiply(&self.values, right, &mut result.values, m, p, n);
+        result
+    }
+}
+
+impl<T> Multiply<Conventional<T>, Conventional<T>> for Conventional<T>
+where
+    T: Element + Number,
+{
+    #[inline]
+    fn multiply(&self, right: &Self) -> Self {
+        let (m, p, n) = (self.rows, self.columns, multiplicand(self, right));
+        let mut result = Conventional::new((m, n));
+        multiply(&self.values, &right.values, &mut result.values, m, p, n);
+        result
+    }
+}
+
+impl<T> Multiply<Vec<T>, Conventional<T>> for Conventional<T>
+where
+    T: Element + Number,
+{
+    #[inline(always)]
+    fn multiply(&self, right: &Vec<T>) -> Self {
+        Multiply::multiply(self, &right[..])
+    }
+}
+
+impl<T> MultiplyInto<Conventional<T>, [T]> for Conventional<T>
+where
+    T: Element + Number,
+{
+    #[inline]
+    fn multiply_into(&self, right: &Self, result: &mut [T]) {
+        let (m, p, n) = (self.rows, self.columns, multiplicand(self, right));
+        if result.len() != m * n {
+            panic!(
+                "expected a result of {} elements but got {}",
+                m * n,
+                result.len()
+            );
+        }
+        multiply(&self.values, &right.values, result, m, p, n)
+    }
+}
+
+impl<T> MultiplyInto<Vec<T>, [T]> for Conventional<T>
+where
+    T: Element + Number,
+{
+    #[inline(always)]
+    fn multiply_into(&self, right: &Vec<T>, result: &mut [T]) {
+        MultiplyInto::multiply_into(self, &right[..], result)
+    }
+}
+
+impl<T> MultiplyInto<[T], [T]> for Conventional<T>
+where
+    T: Element + Number,
+{
+    #[inline]
+    fn multiply_into(&self, right: &[T], result: &mut [T]) {
+        let (m, p) = (self.rows, self.columns);
+        let n = multiplicand!(m, p, right, result);
+        multiply(&self.values, right, result, m, p, n)
+    }
+}
+
+fn multiply<T>(a: &[T], b: &[T], c: &mut [T], m: usize, p: usize, n: usize)
+where
+    T: Element + Number,
+{
+    debug_assert_eq!(a.len(), m * p);
+    debug_assert_eq!(b.len(), p * n);
+    debug_assert_eq!(c.len(), m * n);
+    if m == 0 || p == 0 || n == 0 {
+        return;
+    }
+    if n == 1 {
+        for (column, &factor) in a.chunks(m).zip(b) {
+            accumulate(column, factor, c);
+        }
+        return;
+    }
+    for lo in (0..p).step_by(BLOCK_INSIDES) {
+        let lf = min!(lo + BLOCK_INSIDES, p);
+        for io in (0..m).step_by(BLOCK_ROWS) {
+            let i_f = min!(io + BLOCK_ROWS, m);
+            for j in 0..n {
+                let c = &mut c[(j * m + io)..(j * m + i_f)];
+                for l in lo..lf {
+                    accumulate(&a[(l * m + io)..(l * m + i_f)], b[j * p + l], c);
+                }
+            }
+        }
+    }
+}
+
+#[inline(always)]
+fn accumulate<T>(x: &[T], alpha: T, y: &mut [T])
+where
+    T: Element + Number,
+{
+    for (y, &x) in y.iter_mut().zip(x) {
+        *y = *y + alpha * x;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+
+    #[test]
+    fn multiply() {
+        let matrix = Conventional::from_vec((2, 3), vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let right = Conventional::from_vec(
+            (3, 4),
+            vec![
+                1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0,
+            ],
+        );
+        assert_eq!(
+            matrix.multiply(&right),
+            Conventional::from_vec(
+                (2, 4),
+                vec![22.0, 28.0, 49.0, 64.0, 76.0, 100.0, 103.0, 136.0]
+            )
+        );
+    }
+
+    #[test]
+    fn multiply_into() {
+        let matrix = Conventional::from_vec((2, 3), vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let right = Conventional::from_vec(
+            (3, 4),
+            vec![
+                1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0,
+            ],
+        );
+        let mut result =
+            Conventional::from_vec((2, 4), vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+        matrix.multiply_into(&right, &mut result);
+        assert_eq!(
+            result,
+            Conventional::from_vec(
+                (2, 4),
+                vec![23.0, 30.0, 52.0, 68.0, 81.0, 106.0, 110.0, 144.0],
+            )
+        );
+    }
+
+    #[test]
+    fn multiply_into_vector() {
+        let matrix = Conventional::from_vec(
+            (3, 2),
+            matrix![
+                1.0, 4.0;
+                2.0, 5.0;
+                3.0, 6.0;
+            ],
+        );
+        let mut result = vec![1.0, 1.0, 1.0];
+        matrix.multiply_into(&vec![1.0, 2.0], &mut result);
+        assert_eq!(&result, &[10.0, 13.0, 16.0]);
+    }
+
+    #[test]
+    fn multiply_empty() {
+        let matrix = Conventional::from_vec((0, 3), vec![]);
+        assert_eq!(
+            matrix.multiply(&[1.0, 2.0, 3.0][..]),
+            Conventional::from_vec((0, 1), vec![])
+        );
+        let matrix = Conventional::from_vec((2, 3), vec![1.0; 6]);
+        assert_eq!(
+            matrix.multiply(&Conventional::from_vec((3, 0), vec![])),
+            Conventional::from_vec((2, 0), vec![])
+        );
+    }
+
+    #[test]
+    fn multiply_empty_inside() {
+        let matrix = Conventional::<f64>::from_vec((2, 0), vec![]);
+        let right = Conventional::from_vec((0, 3), vec![]);
+        assert_eq!(matrix.multiply(&right), Conventional::new((2, 3)));
+        let mut result = Conventional::from_vec((2, 3), vec![1.0; 6]);
+        matrix.multiply_into(&right, &mut result);
+        assert_eq!(result, Conventional::from_vec((2, 3), vec![1.0; 6]));
+    }
+
+    #[test]
+    fn multiply_integer() {
+        let matrix = Conventional::from_vec((2, 2), vec![1, 2, 3, 4]);
+        assert_eq!(&*matrix.multiply(&[1, 1, 0, 1][..]), &[4, 6, 3, 4]);
+    }
+
+    #[test]
+    fn multiply_blocked() {
+        let (m, p, n) = (70, 300, 3);
+        let matrix = Conventional::from_vec(
+            (m, p),
+            (0..(m * p)).map(|k| (k % 7) as f64 - 3.0).collect(),
+        );
+        let right = Conventional::from_vec(
+            (p, n),
+            (0..(p * n)).map(|k| (k % 5) as f64 - 2.0).collect(),
+        );
+        let result = matrix.multiply(&right);
+        for i in 0..m {
+            for j in 0..n {
+                let expected = (0..p).fold(0.0, |sum, l| sum + matrix[(i, l)] * right[(l, j)]);
+                assert_eq!(result[(i, j)], expected);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot multiply a 2 × 3 matrix and a slice of 4 elements")]
+    fn multiply_mismatch() {
+        let matrix = Conventional::from_vec((2, 3), vec![1.0; 6]);
+        let _ = matrix.multiply(&[1.0; 4][..]);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot infer the number of columns of a slice multiplying a 2 × 0 matrix")]
+    fn multiply_ambiguous() {
+        let matrix = Conventional::<f64>::from_vec((2, 0), vec![]);
+        let mut result = [];
+        matrix.multiply_into(&[][..], &mut result[..]);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected a result of 4 elements but got 3")]
+    fn multiply_into_mismatch() {
+        let matrix = Conventional::from_vec((2, 3), vec![1.0; 6]);
+        let mut result = [0.0; 3];
+        matrix.multiply_into(&[1.0; 6][..], &mut result[..]);
+    }
+}
@@ -0,0 +1,156 @@
+use num_traits::Float;
+
+use format::conventional::decomposition::lu;
+use format::Conventional;
+use operation::Solve;
+use {Element, Result};
+
+impl<T> Solve<[T], Vec<T>> for Conventional<T>
+where
+    T: Element + Float,
+{
+    fn solve(&self, right: &[T]) -> Result<Vec<T>> {
+        if right.len() != self.rows {
+            raise!(DimensionMismatch {
+                left: (self.rows, self.columns),
+                right: (right.len(), 1),
+            });
+        }
+        let mut result = right.to_vec();
+        solve(self, &mut result, 1)?;
+        Ok(result)
+    }
+}
+
+impl<T> Solve<Vec<T>, Vec<T>> for Conventional<T>
+where
+    T: Element + Float,
+{
+    #[inline(always)]
+    fn solve(&self, right: &Vec<T>) -> Result<Vec<T>> {
+        Solve::solve(self, &right[..])
+    }
+}
+
+impl<T> Solve<Conventional<T>, Conventional<T>> for Conventional<T>
+where
+    T: Element + Float,
+{
+    fn solve(&self, right: &Self) -> Result<Self> {
+        if right.rows != self.rows {
+            raise!(DimensionMismatch {
+                left: (self.rows, self.columns),
+                right: (right.rows, right.columns),
+            });
+        }
+        let mut result = right.clone();
+        solve(self, &mut result.values, right.columns)?;
+        Ok(result)
+    }
+}
+
+fn solve<T>(matrix: &Conventional<T>, values: &mut [T], n: usize) -> Result<()>
+where
+    T: Element + Float,
+{
+    let m = square!(matrix);
+    let mut factors = matrix.values.clone();
+    let mut pivots = vec![0; m];
+    lu::factorize(&mut factors, &mut pivots, m, m)?;
+    lu::substitute(&factors, &pivots, values, m, n);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+    use Error;
+
+    #[test]
+    fn solve_vector() {
+        let matrix = Conventional::from_vec(
+            3,
+            matrix![
+                2.0,  1.0, 1.0;
+                4.0, -6.0, 0.0;
+               -2.0,  7.0, 2.0;
+            ],
+        );
+        let result = matrix.solve(&[5.0, -2.0, 9.0][..]).unwrap();
+        assert::close(&result, &[1.0, 1.0, 2.0][..], 1e-14);
+    }
+
+    #[test]
+    fn solve_matrix() {
+        let matrix = Conventional::from_vec(
+            3,
+            matrix![
+                2.0,  1.0, 1.0;
+                4.0, -6.0, 0.0;
+               -2.0,  7.0, 2.0;
+            ],
+        );
+        let right = Conventional::from_vec(
+            (3, 2),
+            matrix![
+                5.0, 4.0;
+               -2.0, 4.0;
+                9.0, 2.0;
+            ],
+        );
+        let result = matrix.solve(&right).unwrap();
+        assert_eq!((result.rows, result.columns), (3, 2));
+        assert::close(
+            &*result,
+            &*matrix![
+                1.0, 1.0;
+                1.0, 0.0;
+                2.0, 2.0;
+            ],
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn solve_empty() {
+        let matrix = Conventional::<f64>::from_vec(0, vec![]);
+        assert_eq!(matrix.solve(&[][..]).unwrap(), vec![]);
+        let right = Conventional::from_vec((0, 2), vec![]);
+        assert_eq!(matrix.solve(&right).unwrap(), right);
+    }
+
+    #[test]
+    fn solve_mismatch() {
+        let matrix = Conventional::from_vec((2, 3), vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert!(matrix.solve(&vec![1.0, 2.0]).is_err());
+        let matrix = Conventional::from_vec(2, vec![1.0, 2.0, 3.0, 4.0]);
+        assert!(matrix.solve(&vec![1.0, 2.0, 3.0]).is_err());
+    }
+
+    #[test]
+    fn solve_singular() {
+        let matrix = Conventional::from_vec(2, vec![1.0, 2.0, 2.0, 4.0]);
+        assert!(matrix.solve(&vec![1.0, 2.0]).is_err());
+    }
+
+    #[test]
+    fn solve_errors() {
+        let matrix = Conventional::from_vec(2, vec![1.0, 2.0, 2.0, 4.0]);
+        assert_eq!(
+            matrix.solve(&[1.0, 2.0][..]),
+            Err(Error::Singular { pivot: 1 })
+        );
+        assert_eq!(
+            matrix.solve(&[1.0, 2.0, 3.0][..]),
+            Err(Error::DimensionMismatch {
+                left: (2, 2),
+                right: (3, 1),
+            })
+        );
+        let matrix = Conventional::<f64>::new((2, 3));
+        let error = matrix.solve(&[1.0, 2.0][..]).unwrap_err();
+        assert_eq!(error, Error::NotSquare { rows: 2, columns: 3 });
+        assert_eq!(error.to_string(), "expected a square matrix but got 2 × 3");
+    }
+}
@@ -0,0 +1,218 @@
+use format::compressed::Variant;
+use format::{Compressed, Conventional, Coordinate};
+use {Element, Number};
+
+impl<'l, T> From<&'l Coordinate<T>> for Compressed<T>
+where
+    T: Element + Number,
+{
+    #[inline]
+    fn from(matrix: &'l Coordinate<T>) -> Self {
+        matrix.to_compressed(Variant::Column)
+    }
+}
+
+impl<T> From<Coordinate<T>> for Compressed<T>
+where
+    T: Element + Number,
+{
+    #[inline]
+    fn from(matrix: Coordinate<T>) -> Self {
+        (&matrix).into()
+    }
+}
+
+impl<'l, T: Element> From<&'l Compressed<T>> for Coordinate<T> {
+    fn from(matrix: &'l Compressed<T>) -> Self {
+        let mut result = Coordinate::with_capacity((matrix.rows, matrix.columns), matrix.nonzeros);
+        for (i, j, &value) in matrix.iter() {
+            result.push((i, j), value);
+        }
+        result
+    }
+}
+
+impl<T: Element> From<Compressed<T>> for Coordinate<T> {
+    #[inline]
+    fn from(matrix: Compressed<T>) -> Self {
+        (&matrix).into()
+    }
+}
+
+impl<'l, T> From<&'l Coordinate<T>> for Conventional<T>
+where
+    T: Element + Number,
+{
+    fn from(matrix: &'l Coordinate<T>) -> Self {
+        let &Coordinate {
+            rows,
+            columns,
+            ref values,
+            ref indices,
+        } = validate!(matrix);
+        let mut matrix = Conventional::new((rows, columns));
+        for (&(i, j), &value) in indices.iter().zip(values) {
+            let k = j * rows + i;
+            matrix.values[k] = matrix.values[k] + value;
+        }
+        matrix
+    }
+}
+
+impl<T> From<Coordinate<T>> for Conventional<T>
+where
+    T: Element + Number,
+{
+    #[inline]
+    fn from(matrix: Coordinate<T>) -> Self {
+        (&matrix).into()
+    }
+}
+
+impl<T> Coordinate<T>
+where
+    T: Element + Number,
+{
+    /// Convert the matrix into a given variant of the compressed format.
+    ///
+    /// The elements are sorted by two passes of a counting sort, and the
+    /// values of elements sharing the same position are summed up. Elements
+    /// whose values are zero are retained.
+    pub fn to_compressed(&self, variant: Variant) -> Compressed<T> {
+        let &Coordinate {
+            rows,
+            columns,
+            ref values,
+            ref indices,
+        } = validate!(self);
+        let (majors, minors, major, minor): (_, _, Vec<_>, Vec<_>) = match variant {
+            Variant::Column => (
+                columns,
+                rows,
+                indices.iter().map(|&(_, j)| j).collect(),
+                indices.iter().map(|&(i, _)| i).collect(),
+            ),
+            Variant::Row => (
+                rows,
+                columns,
+                indices.iter().map(|&(i, _)| i).collect(),
+                indices.iter().map(|&(_, j)| j).collect(),
+            ),
+        };
+        let order = (0..values.len()).collect::<Vec<_>>();
+        let (order, _) = sort(&minor, minors, &order);
+        let (order, offsets) = sort(&major, majors, &order);
+        let mut result = Compressed::with_capacity((rows, columns), variant, values.len());
+        for j in 0..majors {
+            let start = result.indices.len();
+            for &k in &order[offsets[j]..offsets[j + 1]] {
+                if result.indices.len() > start && result.indices.last() == Some(&minor[k]) {
+                    let last = result.values.last_mut().unwrap();
+                    *last = *last + values[k];
+                } else {
+                    result.indices.push(minor[k]);
+                    result.values.push(values[k]);
+                }
+            }
+            result.offsets[j + 1] = result.indices.len();
+        }
+        result.nonzeros = result.values.len();
+        result
+    }
+}
+
+/// Perform a stable counting sort of positions by their keys.
+///
+/// The function returns the sorted positions and the offsets of each key.
+fn sort(keys: &[usize], count: usize, order: &[usize]) -> (Vec<usize>, Vec<usize>) {
+    let mut offsets = vec![0; count + 1];
+    for &k in order {
+        offsets[keys[k] + 1] += 1;
+    }
+    for i in 0..count {
+        offsets[i + 1] += offsets[i];
+    }
+    let mut positions = offsets[..count].to_vec();
+    let mut result = vec![0; order.len()];
+    for &k in order {
+        let position = &mut positions[keys[k]];
+        result[*position] = k;
+        *position += 1;
+    }
+    (result, offsets)
+}
+
+#[cfg(test)]
+mod tests {
+    use format::compressed::Variant;
+    use prelude::*;
+
+    fn matrix() -> Coordinate<f64> {
+        let mut matrix = Coordinate::new((3, 4));
+        matrix.push((2, 1), 1.0);
+        matrix.push((0, 3), 2.0);
+        matrix.push((1, 1), 3.0);
+        matrix.push((2, 1), 4.0);
+        matrix.push((0, 0), 5.0);
+        matrix.push((1, 1), -3.0);
+        matrix
+    }
+
+    #[test]
+    fn into_compressed_column() {
+        let matrix = Compressed::from(matrix());
+        assert_eq!(
+            matrix,
+            Compressed {
+                rows: 3,
+                columns: 4,
+                nonzeros: 4,
+                variant: Variant::Column,
+                values: vec![5.0, 0.0, 5.0, 2.0],
+                indices: vec![0, 1, 2, 0],
+                offsets: vec![0, 1, 3, 3, 4],
+            }
+        );
+    }
+
+    #[test]
+    fn into_compressed_row() {
+        let matrix = matrix().to_compressed(Variant::Row);
+        assert_eq!(
+            matrix,
+            Compressed {
+                rows: 3,
+                columns: 4,
+                nonzeros: 4,
+                variant: Variant::Row,
+                values: vec![5.0, 2.0, 0.0, 5.0],
+                indices: vec![0, 3, 1, 1],
+                offsets: vec![0, 2, 3, 4],
+            }
+        );
+    }
+
+    #[test]
+    fn into_conventional() {
+        let matrix = Conventional::from(matrix());
+        assert_eq!(
+            matrix,
+            Conventional::from_vec(
+                (3, 4),
+                matrix![
+                    5.0, 0.0, 0.0, 2.0;
+                    0.0, 0.0, 0.0, 0.0;
+                    0.0, 5.0, 0.0, 0.0;
+                ],
+            )
+        );
+    }
+
+    #[test]
+    fn from_compressed() {
+        let matrix = Compressed::from(matrix());
+        let coordinate = Coordinate::from(&matrix);
+        assert_eq!(&coordinate.indices, &[(0, 0), (1, 1), (2, 1), (0, 3)]);
+        assert_eq!(Compressed::from(coordinate), matrix);
+    }
+}
@@ -0,0 +1,130 @@
+//! The coordinate format.
+//!
+//! The format is suitable for assembling sparse matrices. Elements are stored
+//! as a list of triplets in no particular order, and several elements can
+//! share the same position, in which case their values are summed up when
+//! the matrix is converted into another format.
+
+use {Element, Matrix, Number, Position, Result, Size};
+
+/// A coordinate matrix.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Coordinate<T: Element> {
+    /// The number of rows.
+    pub rows: usize,
+    /// The number of columns.
+    pub columns: usize,
+    /// The values of the stored elements.
+    pub values: Vec<T>,
+    /// The row and column indices of the stored elements.
+    pub indices: Vec<(usize, usize)>,
+}
+
+macro_rules! new(
+    ($rows:expr, $columns:expr, $values:expr, $indices:expr) => (
+        Coordinate {
+            rows: $rows,
+            columns: $columns,
+            values: $values,
+            indices: $indices,
+        }
+    );
+);
+
+mod convert;
+
+size!(Coordinate);
+
+impl<T: Element> Coordinate<T> {
+    /// Check that the matrix is valid.
+    pub fn validate(&self) -> Result<()> {
+        if self.values.len() != self.indices.len() {
+            raise!(
+                "expected {} values but got {}",
+                self.indices.len(),
+                self.values.len()
+            );
+        }
+        for &(i, j) in &self.indices {
+            if i >= self.rows || j >= self.columns {
+                raise!(
+                    "the position ({}, {}) is out of the bounds of a {} × {} matrix",
+                    i,
+                    j,
+                    self.rows,
+                    self.columns
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Create a zero matrix.
+    #[inline]
+    pub fn new<S: Size>(size: S) -> Self {
+        Coordinate::with_capacity(size, 0)
+    }
+
+    /// Create a zero matrix with a specific capacity.
+    pub fn with_capacity<S: Size>(size: S, capacity: usize) -> Self {
+        let (rows, columns) = size.dimensions();
+        new!(
+            rows,
+            columns,
+            Vec::with_capacity(capacity),
+            Vec::with_capacity(capacity)
+        )
+    }
+
+    /// Append an element.
+    ///
+    /// If the position is already occupied, the value will be added to the
+    /// existing one upon conversion.
+    #[inline]
+    pub fn push<P: Position>(&mut self, position: P, value: T) {
+        let (i, j) = position.coordinates();
+        debug_assert!(i < self.rows && j < self.columns);
+        self.values.push(value);
+        self.indices.push((i, j));
+    }
+
+    /// Return an iterator over the stored elements.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        self.indices
+            .iter()
+            .zip(&self.values)
+            .map(|(&(i, j), value)| (i, j, value))
+    }
+}
+
+impl<T> Matrix for Coordinate<T>
+where
+    T: Element + Number,
+{
+    type Element = T;
+
+    fn nonzeros(&self) -> usize {
+        ::format::Compressed::from(self).nonzeros()
+    }
+
+    #[inline]
+    fn zero<S: Size>(size: S) -> Self {
+        Coordinate::new(size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+
+    #[test]
+    fn nonzeros() {
+        let mut matrix = Coordinate::new((2, 3));
+        matrix.push((0, 1), 1.0);
+        matrix.push((1, 2), 2.0);
+        matrix.push((0, 1), -1.0);
+        matrix.push((1, 0), 3.0);
+        assert_eq!(matrix.nonzeros(), 2);
+    }
+}
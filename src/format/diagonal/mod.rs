@@ -5,7 +5,7 @@
 use std::ops::{Deref, DerefMut};
 use std::fmt;
 
-use {Element, Matrix, Size};
+use {Element, Matrix, Result, Size};
 
 /// A diagonal matrix.
 #[derive(Clone, Debug, PartialEq)]
@@ -31,16 +31,21 @@ macro_rules! new(
 mod convert;
 mod operation;
 
-#[cfg(debug_assertions)]
-impl<T: Element> ::format::Validate for Diagonal<T> {
-    fn validate(&self) {
-        assert_eq!(self.values.len(), min!(self.rows, self.columns))
-    }
-}
-
 size!(Diagonal);
 
 impl<T: Element> Diagonal<T> {
+    /// Check that the matrix is valid.
+    pub fn validate(&self) -> Result<()> {
+        if self.values.len() != min!(self.rows, self.columns) {
+            raise!(
+                "expected {} values but got {}",
+                min!(self.rows, self.columns),
+                self.values.len()
+            );
+        }
+        Ok(())
+    }
+
     /// Create a zero matrix.
     pub fn new<S: Size>(size: S) -> Self {
         let (rows, columns) = size.dimensions();
@@ -61,6 +66,20 @@ impl<T: Element> Diagonal<T> {
         new!(rows, columns, values)
     }
 
+    /// Create a matrix from a slice checking its length.
+    #[inline]
+    pub fn try_from_slice<S: Size>(size: S, values: &[T]) -> Result<Self> {
+        Diagonal::try_from_vec(size, values.to_vec())
+    }
+
+    /// Create a matrix from a vector checking its length.
+    pub fn try_from_vec<S: Size>(size: S, values: Vec<T>) -> Result<Self> {
+        let (rows, columns) = size.dimensions();
+        let matrix = new!(rows, columns, values);
+        matrix.validate()?;
+        Ok(matrix)
+    }
+
     /// Create a matrix with uninitialized elements.
     pub unsafe fn with_uninitialized<S: Size>(size: S) -> Self {
         let (rows, columns) = size.dimensions();
@@ -133,4 +152,11 @@ mod tests {
         let matrix = Diagonal::from_vec(4, vec![1.0, 2.0, 0.0, 3.0]);
         assert_eq!(matrix.nonzeros(), 3);
     }
+
+    #[test]
+    fn try_from_vec() {
+        assert!(Diagonal::try_from_vec((2, 3), vec![1.0, 2.0]).is_ok());
+        assert!(Diagonal::try_from_vec((2, 3), vec![1.0, 2.0, 3.0]).is_err());
+        assert!(Diagonal::try_from_slice(3, &[1.0]).is_err());
+    }
 }
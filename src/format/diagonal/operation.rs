@@ -1,6 +1,77 @@
+use std::ops::{Add, Sub};
+
+use num_traits::Float;
+
 use format::Diagonal;
-use operation::Transpose;
-use Element;
+use operation::{Determinant, Inverse, Rank, Transpose};
+use {Element, Number, Result};
+
+macro_rules! implement(
+    ($operator:ident, $function:ident, $verb:expr) => (
+        impl<'l, 'r, T> $operator<&'r Diagonal<T>> for &'l Diagonal<T>
+        where
+            T: Element + Number,
+        {
+            type Output = Diagonal<T>;
+
+            fn $function(self, right: &'r Diagonal<T>) -> Self::Output {
+                conform!(self, right, $verb);
+                let mut result = self.clone();
+                for (value, &other) in result.values.iter_mut().zip(&right.values) {
+                    *value = $operator::$function(*value, other);
+                }
+                result
+            }
+        }
+
+        forward!($operator, $function, Diagonal, Diagonal, Diagonal);
+    );
+);
+
+implement!(Add, add, "add");
+implement!(Sub, sub, "subtract");
+
+scalar!(Diagonal);
+
+impl<T> Determinant<T> for Diagonal<T>
+where
+    T: Element + Number,
+{
+    fn determinant(&self) -> Result<T> {
+        square!(self);
+        Ok(self.values.iter().fold(T::one(), |product, &value| product * value))
+    }
+}
+
+impl<T> Inverse for Diagonal<T>
+where
+    T: Element + Float,
+{
+    fn inverse(&self) -> Result<Self> {
+        square!(self);
+        let mut matrix = self.clone();
+        for (k, value) in matrix.values.iter_mut().enumerate() {
+            if Element::is_zero(value) {
+                raise!(Singular { pivot: k });
+            }
+            *value = value.recip();
+        }
+        Ok(matrix)
+    }
+}
+
+impl<T> Rank<T> for Diagonal<T>
+where
+    T: Element + Float,
+{
+    #[inline]
+    fn rank(&self, tolerance: T) -> usize {
+        self.values
+            .iter()
+            .filter(|value| value.abs() > tolerance)
+            .count()
+    }
+}
 
 impl<T: Element> Transpose for Diagonal<T> {
     #[inline(always)]
@@ -8,3 +79,45 @@ impl<T: Element> Transpose for Diagonal<T> {
         self.clone()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+
+    #[test]
+    fn add() {
+        let left = Diagonal::from_vec((2, 3), vec![1.0, 2.0]);
+        let right = Diagonal::from_vec((2, 3), vec![3.0, 5.0]);
+        assert_eq!(&*(&left + &right), &[4.0, 7.0]);
+        assert_eq!(&*(&left - right), &[-2.0, -3.0]);
+        assert_eq!(&*(-(left * 3.0)), &[-3.0, -6.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot subtract a 2 × 2 matrix and a 3 × 3 matrix")]
+    fn add_mismatch() {
+        let _ = Diagonal::<f64>::new(2) - Diagonal::new(3);
+    }
+
+    #[test]
+    fn determinant() {
+        let matrix = Diagonal::from_vec(3, vec![2, -3, 4]);
+        assert_eq!(matrix.determinant().unwrap(), -24);
+        let matrix = Diagonal::from_vec((3, 2), vec![2, 3]);
+        assert!(matrix.determinant().is_err());
+    }
+
+    #[test]
+    fn inverse() {
+        let matrix = Diagonal::from_vec(3, vec![2.0, -4.0, 0.5]);
+        assert_eq!(&*matrix.inverse().unwrap(), &[0.5, -0.25, 2.0]);
+        let matrix = Diagonal::from_vec(2, vec![2.0, 0.0]);
+        assert!(matrix.inverse().is_err());
+    }
+
+    #[test]
+    fn rank() {
+        let matrix = Diagonal::from_vec((4, 3), vec![2.0, 1e-12, -3.0]);
+        assert_eq!(matrix.rank(1e-10), 2);
+    }
+}
@@ -1,10 +1,5 @@
 //! Storage formats.
 
-#[cfg(debug_assertions)]
-trait Validate {
-    fn validate(&self);
-}
-
 macro_rules! buffer(
     ($capacity:expr) => ({
         let capacity = $capacity as usize;
@@ -14,6 +9,94 @@ macro_rules! buffer(
     });
 );
 
+macro_rules! conform(
+    ($left:expr, $right:expr, $operation:expr) => ({
+        let left = ::Size::dimensions($left);
+        let right = ::Size::dimensions($right);
+        if left != right {
+            panic!(
+                "cannot {} a {} × {} matrix and a {} × {} matrix",
+                $operation, left.0, left.1, right.0, right.1
+            );
+        }
+    });
+);
+
+macro_rules! multiplicand(
+    ($rows:expr, $columns:expr, $right:expr) => ({
+        let (rows, columns, length) = ($rows, $columns, $right.len());
+        if columns == 0 {
+            panic!(
+                "cannot infer the number of columns of a slice multiplying a {} × 0 matrix",
+                rows
+            );
+        }
+        if length % columns != 0 {
+            panic!(
+                "cannot multiply a {} × {} matrix and a slice of {} elements",
+                rows, columns, length
+            );
+        }
+        length / columns
+    });
+    ($rows:expr, $columns:expr, $right:expr, $result:expr) => ({
+        let (rows, columns) = ($rows, $columns);
+        let n = multiplicand!(rows, columns, $right);
+        if $result.len() != rows * n {
+            panic!(
+                "expected a result of {} elements but got {}",
+                rows * n,
+                $result.len()
+            );
+        }
+        n
+    });
+);
+
+macro_rules! forward(
+    ($left:ident, $right:ident -> $output:ident) => (
+        forward!(Add, add, $left, $right, $output);
+        forward!(Sub, sub, $left, $right, $output);
+    );
+    ($operator:ident, $function:ident, $left:ident, $right:ident, $output:ident) => (
+        impl<T> ::std::ops::$operator<$right<T>> for $left<T>
+        where
+            T: ::Element + ::Number,
+        {
+            type Output = $output<T>;
+
+            #[inline]
+            fn $function(self, right: $right<T>) -> Self::Output {
+                ::std::ops::$operator::$function(&self, &right)
+            }
+        }
+
+        impl<'r, T> ::std::ops::$operator<&'r $right<T>> for $left<T>
+        where
+            T: ::Element + ::Number,
+        {
+            type Output = $output<T>;
+
+            #[inline]
+            fn $function(self, right: &'r $right<T>) -> Self::Output {
+                ::std::ops::$operator::$function(&self, right)
+            }
+        }
+
+        impl<'l, T> ::std::ops::$operator<$right<T>> for &'l $left<T>
+        where
+            T: ::Element + ::Number,
+        {
+            type Output = $output<T>;
+
+            #[inline]
+            fn $function(self, right: $right<T>) -> Self::Output {
+                ::std::ops::$operator::$function(self, &right)
+            }
+        }
+    );
+);
+
 macro_rules! min(
     ($left:expr, $right:expr) => ({
         let (left, right) = ($left, $right);
@@ -21,6 +104,62 @@ macro_rules! min(
     });
 );
 
+macro_rules! scalar(
+    ($kind:ident) => (
+        impl<T> ::std::ops::Mul<T> for $kind<T>
+        where
+            T: ::Element + ::Number,
+        {
+            type Output = Self;
+
+            fn mul(mut self, right: T) -> Self {
+                for value in &mut self.values {
+                    *value = *value * right;
+                }
+                self
+            }
+        }
+
+        impl<'l, T> ::std::ops::Mul<T> for &'l $kind<T>
+        where
+            T: ::Element + ::Number,
+        {
+            type Output = $kind<T>;
+
+            #[inline]
+            fn mul(self, right: T) -> Self::Output {
+                self.clone() * right
+            }
+        }
+
+        impl<T> ::std::ops::Neg for $kind<T>
+        where
+            T: ::Element + ::Number + ::std::ops::Neg<Output = T>,
+        {
+            type Output = Self;
+
+            fn neg(mut self) -> Self {
+                for value in &mut self.values {
+                    *value = -*value;
+                }
+                self
+            }
+        }
+
+        impl<'l, T> ::std::ops::Neg for &'l $kind<T>
+        where
+            T: ::Element + ::Number + ::std::ops::Neg<Output = T>,
+        {
+            type Output = $kind<T>;
+
+            #[inline]
+            fn neg(self) -> Self::Output {
+                -self.clone()
+            }
+        }
+    );
+);
+
 macro_rules! size(
     ($kind:ident, $rows:ident, $columns:ident) => (
         impl<T: ::Element> ::Size for $kind<T> {
@@ -43,9 +182,10 @@ macro_rules! size(
 #[cfg(debug_assertions)]
 macro_rules! validate(
     ($matrix:expr) => ({
-        use ::format::Validate;
         let matrix = $matrix;
-        matrix.validate();
+        if let Err(error) = matrix.validate() {
+            panic!("{}", error);
+        }
         matrix
     });
 );
@@ -58,11 +198,13 @@ macro_rules! validate(
 pub mod banded;
 pub mod compressed;
 pub mod conventional;
+pub mod coordinate;
 pub mod diagonal;
 pub mod packed;
 
 pub use self::banded::Banded;
 pub use self::compressed::Compressed;
 pub use self::conventional::Conventional;
+pub use self::coordinate::Coordinate;
 pub use self::diagonal::Diagonal;
 pub use self::packed::Packed;
@@ -0,0 +1,86 @@
+use num_traits::Float;
+
+use decomposition::{Cholesky, SymmetricEigen};
+use format::packed::Variant;
+use format::{Conventional, Diagonal, Packed};
+use operation::Transpose;
+use {Element, Result};
+
+impl<T> Cholesky<T> for Packed<T>
+where
+    T: Element + Float,
+{
+    fn decompose(&self, variant: Variant) -> Result<Packed<T>> {
+        let matrix = if self.variant == variant {
+            Conventional::from(self)
+        } else {
+            Conventional::from(self.transpose())
+        };
+        Cholesky::decompose(&matrix, variant)
+    }
+}
+
+impl<T> SymmetricEigen<T> for Packed<T>
+where
+    T: Element,
+    Conventional<T>: SymmetricEigen<T>,
+{
+    fn decompose(&self) -> Result<(Conventional<T>, Diagonal<T>)> {
+        let matrix = match self.variant {
+            Variant::Upper => Conventional::from(self),
+            Variant::Lower => Conventional::from(self.transpose()),
+        };
+        SymmetricEigen::decompose(&matrix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use format::packed::Variant;
+    use prelude::*;
+
+    #[test]
+    fn decompose() {
+        let matrix = new!(
+            3,
+            Variant::Lower,
+            vec![4.0, 12.0, -16.0, 37.0, -43.0, 98.0]
+        );
+        let factor = Cholesky::decompose(&matrix, Variant::Lower).unwrap();
+        assert_eq!(&factor.values, &[2.0, 6.0, -8.0, 1.0, 5.0, 3.0]);
+        let factor = Cholesky::decompose(&matrix, Variant::Upper).unwrap();
+        assert_eq!(&factor.values, &[2.0, 6.0, 1.0, -8.0, 5.0, 3.0]);
+    }
+
+    #[test]
+    fn decompose_indefinite() {
+        let matrix = new!(2, Variant::Upper, vec![1.0, 2.0, 1.0]);
+        assert!(Cholesky::decompose(&matrix, Variant::Lower).is_err());
+    }
+
+    #[test]
+    fn decompose_symmetric() {
+        let lower = new!(3, Variant::Lower, vec![2.0, -1.0, 0.0, 2.0, -1.0, 2.0]);
+        let upper = new!(3, Variant::Upper, vec![2.0, -1.0, 2.0, 0.0, -1.0, 2.0]);
+        let expected = [2.0 - 2f64.sqrt(), 2.0, 2.0 + 2f64.sqrt()];
+        for matrix in &[lower, upper] {
+            let (vectors, values) = SymmetricEigen::decompose(matrix).unwrap();
+            assert::close(&*values, &expected[..], 1e-14);
+            let matrix = Conventional::from_vec(
+                3,
+                matrix![
+                     2.0, -1.0,  0.0;
+                    -1.0,  2.0, -1.0;
+                     0.0, -1.0,  2.0;
+                ],
+            );
+            let product = matrix.multiply(&vectors);
+            for j in 0..3 {
+                for i in 0..3 {
+                    assert::close(product[(i, j)], values[j] * vectors[(i, j)], 1e-14);
+                }
+            }
+        }
+    }
+}
@@ -7,7 +7,7 @@
 //! [2]: http://www.netlib.org/lapack
 
 use std::fmt;
-use {Element, Matrix, Size};
+use {Element, Matrix, Result, Size};
 
 /// A packed matrix.
 #[derive(Clone, Debug, PartialEq)]
@@ -38,6 +38,7 @@ macro_rules! storage(
 );
 
 mod convert;
+mod decomposition;
 mod operation;
 
 /// A variant of a packed matrix.
@@ -49,16 +50,21 @@ pub enum Variant {
     Upper,
 }
 
-#[cfg(debug_assertions)]
-impl<T: Element> ::format::Validate for Packed<T> {
-    fn validate(&self) {
-        assert_eq!(self.values.len(), storage!(self.size));
-    }
-}
-
 size!(Packed, size, size);
 
 impl<T: Element> Packed<T> {
+    /// Check that the matrix is valid.
+    pub fn validate(&self) -> Result<()> {
+        if self.values.len() != storage!(self.size) {
+            raise!(
+                "expected {} values but got {}",
+                storage!(self.size),
+                self.values.len()
+            );
+        }
+        Ok(())
+    }
+
     /// Create a zero matrix.
     pub fn new<S: Size>(size: S, variant: Variant) -> Self {
         let (rows, _columns) = size.dimensions();
@@ -143,4 +149,12 @@ mod tests {
         );
         assert_eq!(matrix.nonzeros(), 7);
     }
+
+    #[test]
+    fn validate() {
+        let mut matrix = Packed::<f64>::new(3, Variant::Lower);
+        assert!(matrix.validate().is_ok());
+        matrix.values.push(1.0);
+        assert!(matrix.validate().is_err());
+    }
 }
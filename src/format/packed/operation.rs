@@ -1,7 +1,119 @@
+use std::ops::{Add, Sub};
+
+use num_traits::Float;
+
 use format::packed::Variant;
-use format::Packed;
-use operation::Transpose;
-use Element;
+use format::{Conventional, Packed};
+use operation::{Determinant, Inverse, MultiplyInto, Rank, Transpose};
+use {Element, Number, Result};
+
+macro_rules! implement(
+    ($operator:ident, $function:ident, $verb:expr) => (
+        impl<'l, 'r, T> $operator<&'r Packed<T>> for &'l Packed<T>
+        where
+            T: Element + Number,
+        {
+            type Output = Packed<T>;
+
+            fn $function(self, right: &'r Packed<T>) -> Self::Output {
+                conform!(self, right, $verb);
+                if self.variant != right.variant {
+                    panic!(
+                        "cannot {} packed matrices of different variants ({:?} and {:?})",
+                        $verb, self.variant, right.variant
+                    );
+                }
+                let mut result = self.clone();
+                for (value, &other) in result.values.iter_mut().zip(&right.values) {
+                    *value = $operator::$function(*value, other);
+                }
+                result
+            }
+        }
+
+        forward!($operator, $function, Packed, Packed, Packed);
+    );
+);
+
+implement!(Add, add, "add");
+implement!(Sub, sub, "subtract");
+
+scalar!(Packed);
+
+impl<T> Determinant<T> for Packed<T>
+where
+    T: Element + Number,
+{
+    fn determinant(&self) -> Result<T> {
+        let &Packed {
+            size,
+            variant,
+            ref values,
+        } = self;
+        Ok((0..size).fold(T::one(), |product, j| {
+            product * values[diagonal(size, variant, j)]
+        }))
+    }
+}
+
+impl<T> MultiplyInto<[T], [T]> for Packed<T>
+where
+    T: Element + Number,
+{
+    fn multiply_into(&self, right: &[T], result: &mut [T]) {
+        let &Packed {
+            size,
+            variant,
+            ref values,
+        } = self;
+        let n = multiplicand!(size, size, right, result);
+        for k in 0..n {
+            let (right, result) = (&right[(k * size)..], &mut result[(k * size)..]);
+            let mut values = values.iter();
+            for (j, &factor) in right[..size].iter().enumerate() {
+                let rows = match variant {
+                    Variant::Lower => j..size,
+                    Variant::Upper => 0..(j + 1),
+                };
+                for i in rows {
+                    let value = *values.next().unwrap();
+                    result[i] = result[i] + value * factor;
+                }
+            }
+        }
+    }
+}
+
+impl<T> Inverse for Packed<T>
+where
+    T: Element + Float,
+{
+    fn inverse(&self) -> Result<Self> {
+        let &Packed { size, variant, .. } = self;
+        for j in 0..size {
+            if Element::is_zero(&self.values[diagonal(size, variant, j)]) {
+                raise!(Singular { pivot: j });
+            }
+        }
+        Ok(match variant {
+            Variant::Lower => new!(size, variant, invert(&self.values, size)),
+            Variant::Upper => {
+                let matrix = self.transpose();
+                new!(size, Variant::Lower, invert(&matrix.values, size)).transpose()
+            }
+        })
+    }
+}
+
+impl<T> Rank<T> for Packed<T>
+where
+    T: Element + Float,
+{
+    #[inline]
+    fn rank(&self, tolerance: T) -> usize {
+        Conventional::from(self).rank(tolerance)
+    }
+}
 
 impl<T: Element> Transpose for Packed<T> {
     fn transpose(&self) -> Self {
@@ -23,11 +135,91 @@ impl<T: Element> Transpose for Packed<T> {
     }
 }
 
+#[inline]
+fn diagonal(size: usize, variant: Variant, j: usize) -> usize {
+    match variant {
+        Variant::Lower => arithmetic!(j, size, size - j + 1),
+        Variant::Upper => arithmetic!(j + 1, 1, j + 1) - 1,
+    }
+}
+
+fn invert<T>(values: &[T], size: usize) -> Vec<T>
+where
+    T: Element + Float,
+{
+    let mut result = vec![<T as Element>::zero(); values.len()];
+    for j in 0..size {
+        let start = diagonal(size, Variant::Lower, j);
+        let column = &mut result[start..(start + size - j)];
+        column[0] = T::one();
+        for k in j..size {
+            let offset = diagonal(size, Variant::Lower, k);
+            let value = column[k - j] / values[offset];
+            column[k - j] = value;
+            for i in (k + 1)..size {
+                column[i - j] = column[i - j] - values[offset + i - k] * value;
+            }
+        }
+    }
+    result
+}
+
 #[cfg(test)]
 mod tests {
+    use assert;
     use format::packed::Variant;
     use prelude::*;
 
+    #[test]
+    fn add() {
+        let left = new!(2, Variant::Upper, vec![1.0, 2.0, 3.0]);
+        let right = new!(2, Variant::Upper, vec![3.0, 2.0, 1.0]);
+        assert_eq!(&(&left + &right).values, &[4.0, 4.0, 4.0]);
+        assert_eq!(&(-(left - right) * 0.5).values, &[1.0, 0.0, -1.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot add packed matrices of different variants")]
+    fn add_mismatch() {
+        let left = new!(2, Variant::Lower, vec![1.0, 2.0, 3.0]);
+        let right = new!(2, Variant::Upper, vec![3.0, 2.0, 1.0]);
+        let _ = left + right;
+    }
+
+    #[test]
+    fn determinant() {
+        let matrix = new!(3, Variant::Lower, vec![2.0, 1.0, 1.0, 3.0, 1.0, 4.0]);
+        assert_eq!(matrix.determinant().unwrap(), 24.0);
+        let matrix = new!(3, Variant::Upper, vec![2.0, 1.0, 3.0, 1.0, 1.0, 4.0]);
+        assert_eq!(matrix.determinant().unwrap(), 24.0);
+    }
+
+    #[test]
+    fn inverse_lower() {
+        let matrix = new!(3, Variant::Lower, vec![2.0, 1.0, 1.0, 4.0, 2.0, 8.0]);
+        let inverse = matrix.inverse().unwrap();
+        assert_eq!(inverse.variant, Variant::Lower);
+        let product = Conventional::from(&matrix).multiply(&Conventional::from(&inverse));
+        assert::close(&*product, &[1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0][..], 1e-14);
+    }
+
+    #[test]
+    fn inverse_upper() {
+        let matrix = new!(3, Variant::Upper, vec![2.0, 1.0, 4.0, 1.0, 2.0, 8.0]);
+        let inverse = matrix.inverse().unwrap();
+        assert_eq!(inverse.variant, Variant::Upper);
+        let product = Conventional::from(&matrix).multiply(&Conventional::from(&inverse));
+        assert::close(&*product, &[1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0][..], 1e-14);
+        let matrix = new!(2, Variant::Upper, vec![2.0, 1.0, 0.0]);
+        assert!(matrix.inverse().is_err());
+    }
+
+    #[test]
+    fn rank() {
+        let matrix = new!(3, Variant::Lower, vec![1.0, 2.0, 3.0, 0.0, 0.0, 0.0]);
+        assert_eq!(matrix.rank(1e-10), 1);
+    }
+
     #[test]
     fn transpose_lower() {
         let matrix = new!(
@@ -63,4 +255,17 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn multiply_into() {
+        let right = [1.0, 2.0, 3.0];
+        let matrix = new!(3, Variant::Lower, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let mut result = vec![1.0; 3];
+        matrix.multiply_into(&right[..], &mut result[..]);
+        assert_eq!(result, vec![2.0, 11.0, 32.0]);
+        let matrix = new!(3, Variant::Upper, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let mut result = vec![0.0; 3];
+        matrix.multiply_into(&right[..], &mut result[..]);
+        assert_eq!(result, vec![17.0, 21.0, 18.0]);
+    }
 }
@@ -0,0 +1,124 @@
+use num_traits::Float;
+
+use operation::MultiplyInto;
+use {Element, Result, Size};
+
+use super::{Options, Preconditioner, Report};
+
+/// Solve a system of linear equations using the biconjugate-gradient
+/// stabilized method.
+///
+/// The preconditioner, if any, is applied on the right. The solution is
+/// initialized with the content of `solution`, which is overwritten with the
+/// result. The iterations stop early if the method breaks down, in which case
+/// the report indicates no convergence.
+pub fn bicgstab<T, A>(
+    matrix: &A,
+    right: &[T],
+    solution: &mut [T],
+    preconditioner: Option<&dyn Preconditioner<T>>,
+    options: &Options<T>,
+) -> Result<Report<T>>
+where
+    T: Element + Float,
+    A: MultiplyInto<[T], [T]> + Size + ?Sized,
+{
+    let scale = super::check(matrix, right, solution)?;
+    if Element::is_zero(&scale) {
+        return Ok(super::trivial(solution));
+    }
+    let n = right.len();
+    let zero = <T as Element>::zero();
+    let mut r = vec![zero; n];
+    super::residual(matrix, right, solution, &mut r);
+    let shadow = r.clone();
+    let (mut p, mut v) = (vec![zero; n], vec![zero; n]);
+    let (mut y, mut z, mut t) = (vec![zero; n], vec![zero; n], vec![zero; n]);
+    let (mut rho, mut alpha, mut omega) = (T::one(), T::one(), T::one());
+    let mut residual = super::norm(&r) / scale;
+    let mut iterations = 0;
+    while residual > options.tolerance && iterations < options.iterations {
+        iterations += 1;
+        let next = super::dot(&shadow, &r);
+        if Element::is_zero(&next) || Element::is_zero(&omega) {
+            break;
+        }
+        let beta = (next / rho) * (alpha / omega);
+        rho = next;
+        for i in 0..n {
+            p[i] = r[i] + beta * (p[i] - omega * v[i]);
+        }
+        super::precondition(preconditioner, &p, &mut y);
+        super::multiply(matrix, &y, &mut v);
+        let projection = super::dot(&shadow, &v);
+        if Element::is_zero(&projection) {
+            break;
+        }
+        alpha = rho / projection;
+        for i in 0..n {
+            solution[i] = solution[i] + alpha * y[i];
+            r[i] = r[i] - alpha * v[i];
+        }
+        residual = super::norm(&r) / scale;
+        if residual <= options.tolerance {
+            break;
+        }
+        super::precondition(preconditioner, &r, &mut z);
+        super::multiply(matrix, &z, &mut t);
+        let energy = super::dot(&t, &t);
+        if Element::is_zero(&energy) {
+            break;
+        }
+        omega = super::dot(&t, &r) / energy;
+        for i in 0..n {
+            solution[i] = solution[i] + omega * z[i];
+            r[i] = r[i] - omega * t[i];
+        }
+        residual = super::norm(&r) / scale;
+    }
+    Ok(Report {
+        converged: residual <= options.tolerance,
+        iterations,
+        residual,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use format::compressed::tests::tridiagonal;
+    use iterative::tests::system;
+    use iterative::{self, Options};
+    use prelude::*;
+
+    #[test]
+    fn solve() {
+        let matrix = tridiagonal(50, -1.5, 2.5, -0.5);
+        let (expected, right) = system(&matrix);
+        let mut solution = vec![0.0; 50];
+        let options = Options {
+            tolerance: 1e-12,
+            ..Options::default()
+        };
+        let report = iterative::bicgstab(&matrix, &right, &mut solution, None, &options).unwrap();
+        assert!(report.converged);
+        assert!(report.residual <= 1e-12);
+        assert::close(&solution, &expected, 1e-9);
+    }
+
+    #[test]
+    fn solve_banded() {
+        let mut matrix = Banded::new(20, 1, 1);
+        for (i, j, &value) in tridiagonal(20, -1.5, 2.5, -0.5).iter() {
+            matrix.values[j * 3 + 1 + i - j] = value;
+        }
+        let right = vec![1.0; 20];
+        let mut solution = vec![0.0; 20];
+        let report =
+            iterative::bicgstab(&matrix, &right, &mut solution, None, &Options::default()).unwrap();
+        assert!(report.converged);
+        let mut product = vec![0.0; 20];
+        matrix.multiply_into(&solution[..], &mut product[..]);
+        assert::close(&product, &right, 1e-7);
+    }
+}
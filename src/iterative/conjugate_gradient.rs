@@ -0,0 +1,137 @@
+use num_traits::Float;
+
+use operation::MultiplyInto;
+use {Element, Result, Size};
+
+use super::{Options, Preconditioner, Report};
+
+/// Solve a system of linear equations using the conjugate-gradient method.
+///
+/// The coefficient matrix and the preconditioner, if any, are assumed to be
+/// symmetric positive definite; a packed matrix does not qualify, since it is
+/// treated as triangular. The solution is initialized with the content of
+/// `solution`, which is overwritten with the result.
+pub fn conjugate_gradient<T, A>(
+    matrix: &A,
+    right: &[T],
+    solution: &mut [T],
+    preconditioner: Option<&dyn Preconditioner<T>>,
+    options: &Options<T>,
+) -> Result<Report<T>>
+where
+    T: Element + Float,
+    A: MultiplyInto<[T], [T]> + Size + ?Sized,
+{
+    let scale = super::check(matrix, right, solution)?;
+    if Element::is_zero(&scale) {
+        return Ok(super::trivial(solution));
+    }
+    let n = right.len();
+    let mut r = vec![<T as Element>::zero(); n];
+    let mut z = vec![<T as Element>::zero(); n];
+    let mut q = vec![<T as Element>::zero(); n];
+    super::residual(matrix, right, solution, &mut r);
+    super::precondition(preconditioner, &r, &mut z);
+    let mut p = z.clone();
+    let mut rho = super::dot(&r, &z);
+    let mut residual = super::norm(&r) / scale;
+    let mut iterations = 0;
+    while residual > options.tolerance && iterations < options.iterations {
+        iterations += 1;
+        super::multiply(matrix, &p, &mut q);
+        let curvature = super::dot(&p, &q);
+        if Element::is_zero(&curvature) {
+            break;
+        }
+        let alpha = rho / curvature;
+        for i in 0..n {
+            solution[i] = solution[i] + alpha * p[i];
+            r[i] = r[i] - alpha * q[i];
+        }
+        residual = super::norm(&r) / scale;
+        if residual <= options.tolerance {
+            break;
+        }
+        super::precondition(preconditioner, &r, &mut z);
+        let next = super::dot(&r, &z);
+        let beta = next / rho;
+        rho = next;
+        for i in 0..n {
+            p[i] = z[i] + beta * p[i];
+        }
+    }
+    Ok(Report {
+        converged: residual <= options.tolerance,
+        iterations,
+        residual,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use format::compressed::tests::tridiagonal;
+    use iterative::tests::system;
+    use iterative::{self, Options};
+    use prelude::*;
+
+    #[test]
+    fn solve() {
+        let matrix = tridiagonal(50, -1.0, 2.0, -1.0);
+        let (expected, right) = system(&matrix);
+        let mut solution = vec![0.0; 50];
+        let options = Options {
+            tolerance: 1e-12,
+            ..Options::default()
+        };
+        let report =
+            iterative::conjugate_gradient(&matrix, &right, &mut solution, None, &options).unwrap();
+        assert!(report.converged);
+        assert!(report.iterations <= 50);
+        assert!(report.residual <= 1e-12);
+        assert::close(&solution, &expected, 1e-9);
+    }
+
+    #[test]
+    fn solve_conventional() {
+        let matrix = Conventional::from(&tridiagonal(10, -1.0, 4.0, -1.0));
+        let right = vec![1.0; 10];
+        let mut solution = vec![0.0; 10];
+        let report = iterative::conjugate_gradient(
+            &matrix,
+            &right,
+            &mut solution,
+            None,
+            &Options::default(),
+        )
+        .unwrap();
+        assert!(report.converged);
+        assert::close(&*matrix.multiply(&solution[..]), &right, 1e-7);
+    }
+
+    #[test]
+    fn solve_limit() {
+        let matrix = tridiagonal(50, -1.0, 2.0, -1.0);
+        let (_, right) = system(&matrix);
+        let mut solution = vec![0.0; 50];
+        let options = Options {
+            iterations: 3,
+            ..Options::default()
+        };
+        let report =
+            iterative::conjugate_gradient(&matrix, &right, &mut solution, None, &options).unwrap();
+        assert!(!report.converged);
+        assert_eq!(report.iterations, 3);
+    }
+
+    #[test]
+    fn solve_mismatch() {
+        let matrix = tridiagonal(5, -1.0, 2.0, -1.0);
+        let mut solution = vec![0.0; 5];
+        let options = Options::default();
+        assert!(
+            iterative::conjugate_gradient(&matrix, &[1.0; 4], &mut solution, None, &options)
+                .is_err()
+        );
+    }
+}
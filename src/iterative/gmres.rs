@@ -0,0 +1,165 @@
+use num_traits::Float;
+
+use operation::MultiplyInto;
+use {Element, Result, Size};
+
+use super::{Options, Preconditioner, Report};
+
+/// Solve a system of linear equations using the generalized minimal residual
+/// method with restarts.
+///
+/// The Krylov subspace is rebuilt from the current residual after every
+/// `options.restart` iterations. The preconditioner, if any, is applied on the
+/// right. The solution is initialized with the content of `solution`, which is
+/// overwritten with the result.
+pub fn gmres<T, A>(
+    matrix: &A,
+    right: &[T],
+    solution: &mut [T],
+    preconditioner: Option<&dyn Preconditioner<T>>,
+    options: &Options<T>,
+) -> Result<Report<T>>
+where
+    T: Element + Float,
+    A: MultiplyInto<[T], [T]> + Size + ?Sized,
+{
+    let scale = super::check(matrix, right, solution)?;
+    if Element::is_zero(&scale) {
+        return Ok(super::trivial(solution));
+    }
+    if options.restart == 0 {
+        raise!("expected a positive number of iterations between restarts");
+    }
+    let (n, m) = (right.len(), options.restart);
+    let zero = <T as Element>::zero();
+    let mut basis = vec![zero; n * (m + 1)];
+    let mut hessenberg = vec![zero; (m + 1) * m];
+    let (mut cosines, mut sines) = (vec![zero; m], vec![zero; m]);
+    let mut g = vec![zero; m + 1];
+    let (mut w, mut z) = (vec![zero; n], vec![zero; n]);
+    let mut r = vec![zero; n];
+    super::residual(matrix, right, solution, &mut r);
+    let mut residual = super::norm(&r) / scale;
+    let mut iterations = 0;
+    while residual > options.tolerance && iterations < options.iterations {
+        let beta = super::norm(&r);
+        for (target, &value) in basis[..n].iter_mut().zip(&r) {
+            *target = value / beta;
+        }
+        for value in g.iter_mut() {
+            *value = zero;
+        }
+        g[0] = beta;
+        let mut k = 0;
+        while k < m && iterations < options.iterations {
+            iterations += 1;
+            super::precondition(preconditioner, &basis[(k * n)..((k + 1) * n)], &mut z);
+            super::multiply(matrix, &z, &mut w);
+            let column = &mut hessenberg[(k * (m + 1))..((k + 1) * (m + 1))];
+            for i in 0..(k + 1) {
+                let vector = &basis[(i * n)..((i + 1) * n)];
+                column[i] = super::dot(&w, vector);
+                for (value, &other) in w.iter_mut().zip(vector) {
+                    *value = *value - column[i] * other;
+                }
+            }
+            let norm = super::norm(&w);
+            column[k + 1] = norm;
+            if !Element::is_zero(&norm) {
+                for (target, &value) in basis[((k + 1) * n)..((k + 2) * n)].iter_mut().zip(&w) {
+                    *target = value / norm;
+                }
+            }
+            for i in 0..k {
+                let (x, y) = (column[i], column[i + 1]);
+                column[i] = cosines[i] * x + sines[i] * y;
+                column[i + 1] = cosines[i] * y - sines[i] * x;
+            }
+            let radius = column[k].hypot(column[k + 1]);
+            if Element::is_zero(&radius) {
+                cosines[k] = T::one();
+                sines[k] = zero;
+            } else {
+                cosines[k] = column[k] / radius;
+                sines[k] = column[k + 1] / radius;
+            }
+            column[k] = radius;
+            column[k + 1] = zero;
+            g[k + 1] = -sines[k] * g[k];
+            g[k] = cosines[k] * g[k];
+            k += 1;
+            residual = g[k].abs() / scale;
+            if residual <= options.tolerance || Element::is_zero(&norm) {
+                break;
+            }
+        }
+        for i in (0..k).rev() {
+            let mut sum = g[i];
+            for j in (i + 1)..k {
+                sum = sum - hessenberg[j * (m + 1) + i] * g[j];
+            }
+            g[i] = sum / hessenberg[i * (m + 1) + i];
+        }
+        for value in w.iter_mut() {
+            *value = zero;
+        }
+        for j in 0..k {
+            for (value, &other) in w.iter_mut().zip(&basis[(j * n)..((j + 1) * n)]) {
+                *value = *value + g[j] * other;
+            }
+        }
+        super::precondition(preconditioner, &w, &mut z);
+        for (value, &update) in solution.iter_mut().zip(&z) {
+            *value = *value + update;
+        }
+        super::residual(matrix, right, solution, &mut r);
+        residual = super::norm(&r) / scale;
+    }
+    Ok(Report {
+        converged: residual <= options.tolerance,
+        iterations,
+        residual,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use format::packed::Variant;
+    use format::compressed::tests::tridiagonal;
+    use iterative::tests::system;
+    use iterative::{self, Options};
+    use prelude::*;
+
+    #[test]
+    fn solve() {
+        let matrix = tridiagonal(50, -1.5, 2.5, -0.5);
+        let (expected, right) = system(&matrix);
+        let mut solution = vec![0.0; 50];
+        let options = Options {
+            tolerance: 1e-12,
+            restart: 10,
+            ..Options::default()
+        };
+        let report = iterative::gmres(&matrix, &right, &mut solution, None, &options).unwrap();
+        assert!(report.converged);
+        assert!(report.residual <= 1e-12);
+        assert::close(&solution, &expected, 1e-9);
+    }
+
+    #[test]
+    fn solve_packed() {
+        let matrix = Packed {
+            size: 3,
+            variant: Variant::Upper,
+            values: vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+        };
+        let right = vec![17.0, 21.0, 18.0];
+        let mut solution = vec![0.0; 3];
+        let report =
+            iterative::gmres(&matrix, &right, &mut solution, None, &Options::default()).unwrap();
+        assert!(report.converged);
+        assert_eq!(report.iterations, 3);
+        assert::close(&solution, &[1.0, 2.0, 3.0][..], 1e-12);
+    }
+}
@@ -0,0 +1,228 @@
+//! Iterative solvers.
+//!
+//! The solvers find the solution of a system of linear equations using only
+//! products of the coefficient matrix with vectors, which makes them suitable
+//! for large sparse systems. Any matrix implementing `MultiplyInto<[T], [T]>`
+//! can serve as the coefficient matrix, and the convergence can be
+//! accelerated by supplying a [preconditioner](trait.Preconditioner.html).
+//! Note that a packed matrix multiplies as the triangular matrix it stores,
+//! in agreement with its conversion into the conventional format; a symmetric
+//! matrix should be passed in another format.
+//!
+//! The following solvers are available:
+//!
+//! * [conjugate gradient][1], suitable for symmetric positive-definite
+//!   matrices;
+//!
+//! * [BiCGSTAB][2], suitable for general matrices; and
+//!
+//! * [GMRES][3] with restarts, suitable for general matrices.
+//!
+//! [1]: fn.conjugate_gradient.html
+//! [2]: fn.bicgstab.html
+//! [3]: fn.gmres.html
+//!
+//! The following preconditioners can be constructed from a compressed matrix:
+//!
+//! * [Jacobi][4], which is the diagonal of the matrix;
+//!
+//! * [incomplete LU][5] with no fill-in, ILU(0); and
+//!
+//! * [incomplete Cholesky][6] with no fill-in, IC(0), suitable for symmetric
+//!   positive-definite matrices.
+//!
+//! [4]: fn.jacobi.html
+//! [5]: struct.IncompleteLU.html
+//! [6]: struct.IncompleteCholesky.html
+
+use num_traits::Float;
+
+use operation::MultiplyInto;
+use {Element, Result, Size};
+
+/// A preconditioner.
+pub trait Preconditioner<T: Element> {
+    /// Apply the inverse of the preconditioner.
+    ///
+    /// The function overwrites `result` with `M⁻¹ × right` where `M` is the
+    /// preconditioner.
+    fn apply_inverse(&self, right: &[T], result: &mut [T]);
+}
+
+/// The options of an iterative solver.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Options<T> {
+    /// The tolerance on the Euclidean norm of the residual relative to the one
+    /// of the right-hand side.
+    pub tolerance: T,
+    /// The maximum number of iterations.
+    pub iterations: usize,
+    /// The number of iterations after which GMRES is restarted.
+    pub restart: usize,
+}
+
+/// A report on the convergence of an iterative solver.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Report<T> {
+    /// The flag indicating whether the tolerance has been reached.
+    pub converged: bool,
+    /// The number of iterations performed.
+    pub iterations: usize,
+    /// The Euclidean norm of the residual relative to the one of the
+    /// right-hand side.
+    pub residual: T,
+}
+
+mod bicgstab;
+mod conjugate_gradient;
+mod gmres;
+mod preconditioner;
+
+pub use self::bicgstab::bicgstab;
+pub use self::conjugate_gradient::conjugate_gradient;
+pub use self::gmres::gmres;
+pub use self::preconditioner::{jacobi, IncompleteCholesky, IncompleteLU};
+
+impl<T: Float> Default for Options<T> {
+    fn default() -> Self {
+        Options {
+            tolerance: T::epsilon().sqrt(),
+            iterations: 1000,
+            restart: 30,
+        }
+    }
+}
+
+/// Check the dimensions of a system and compute the norm of the right-hand
+/// side.
+fn check<T, A>(matrix: &A, right: &[T], solution: &[T]) -> Result<T>
+where
+    T: Element + Float,
+    A: Size + ?Sized,
+{
+    let (rows, columns) = matrix.dimensions();
+    square!(rows, columns);
+    if right.len() != rows {
+        raise!(DimensionMismatch {
+            left: (rows, columns),
+            right: (right.len(), 1),
+        });
+    }
+    if solution.len() != columns {
+        raise!(DimensionMismatch {
+            left: (rows, columns),
+            right: (solution.len(), 1),
+        });
+    }
+    Ok(norm(right))
+}
+
+/// Zero the solution and report convergence for a zero right-hand side.
+fn trivial<T>(solution: &mut [T]) -> Report<T>
+where
+    T: Element,
+{
+    for value in solution.iter_mut() {
+        *value = T::zero();
+    }
+    Report {
+        converged: true,
+        iterations: 0,
+        residual: T::zero(),
+    }
+}
+
+/// Compute `right - matrix × solution`.
+fn residual<T, A>(matrix: &A, right: &[T], solution: &[T], result: &mut [T])
+where
+    T: Element + Float,
+    A: MultiplyInto<[T], [T]> + ?Sized,
+{
+    for value in result.iter_mut() {
+        *value = <T as Element>::zero();
+    }
+    matrix.multiply_into(solution, result);
+    for (value, &right) in result.iter_mut().zip(right) {
+        *value = right - *value;
+    }
+}
+
+/// Compute `matrix × vector`.
+fn multiply<T, A>(matrix: &A, vector: &[T], result: &mut [T])
+where
+    T: Element + Float,
+    A: MultiplyInto<[T], [T]> + ?Sized,
+{
+    for value in result.iter_mut() {
+        *value = <T as Element>::zero();
+    }
+    matrix.multiply_into(vector, result);
+}
+
+/// Apply the inverse of a preconditioner if any.
+fn precondition<T>(preconditioner: Option<&dyn Preconditioner<T>>, right: &[T], result: &mut [T])
+where
+    T: Element,
+{
+    match preconditioner {
+        Some(preconditioner) => preconditioner.apply_inverse(right, result),
+        None => result.copy_from_slice(right),
+    }
+}
+
+fn dot<T>(left: &[T], right: &[T]) -> T
+where
+    T: Element + Float,
+{
+    left.iter()
+        .zip(right)
+        .fold(<T as Element>::zero(), |sum, (&x, &y)| sum + x * y)
+}
+
+fn norm<T>(vector: &[T]) -> T
+where
+    T: Element + Float,
+{
+    dot(vector, vector).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use format::compressed::tests::tridiagonal;
+    use iterative::{self, Options, Report};
+    use prelude::*;
+
+    /// Create a right-hand side corresponding to a known solution.
+    pub fn system(matrix: &Compressed<f64>) -> (Vec<f64>, Vec<f64>) {
+        let solution = (0..matrix.rows)
+            .map(|i| 1.0 + (i as f64).sin())
+            .collect::<Vec<_>>();
+        let mut right = vec![0.0; matrix.rows];
+        matrix.multiply_into(&solution[..], &mut right[..]);
+        (solution, right)
+    }
+
+    #[test]
+    fn solve_zero() {
+        let matrix = tridiagonal(5, -1.0, 2.0, -1.0);
+        let right = vec![0.0; 5];
+        let options = Options::default();
+        let expected = Report {
+            converged: true,
+            iterations: 0,
+            residual: 0.0,
+        };
+        let mut solution = vec![1.0; 5];
+        let report = iterative::conjugate_gradient(&matrix, &right, &mut solution, None, &options);
+        assert_eq!(report.unwrap(), expected);
+        assert_eq!(solution, right);
+        let mut solution = vec![1.0; 5];
+        let report = iterative::bicgstab(&matrix, &right, &mut solution, None, &options);
+        assert_eq!(report.unwrap(), expected);
+        assert_eq!(solution, right);
+        let mut solution = vec![1.0; 5];
+        let report = iterative::gmres(&matrix, &right, &mut solution, None, &options);
+        assert_eq!(report.unwrap(), expected);
+        assert_eq!(solution, right);
+    }
+}
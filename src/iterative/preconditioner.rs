@@ -0,0 +1,372 @@
+use num_traits::Float;
+
+use format::compressed::Variant;
+use format::{Compressed, Diagonal};
+use {Element, Result};
+
+use super::Preconditioner;
+
+/// An incomplete Cholesky factorization with no fill-in, IC(0).
+///
+/// The factor is a lower-triangular matrix `L` with the sparsity pattern of
+/// the lower triangle of the original matrix such that `L × Lᵀ` approximates
+/// the original matrix.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IncompleteCholesky<T: Element> {
+    /// The lower-triangular factor in the compressed-row variant.
+    pub factor: Compressed<T>,
+}
+
+/// An incomplete LU factorization with no fill-in, ILU(0).
+///
+/// The factors are a unit lower-triangular matrix `L` and an upper-triangular
+/// matrix `U` with the sparsity pattern of the original matrix such that
+/// `L × U` approximates the original matrix.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IncompleteLU<T: Element> {
+    /// The strictly lower triangle of `L` and the upper triangle of `U`
+    /// combined in the compressed-row variant.
+    pub factors: Compressed<T>,
+    diagonal: Vec<usize>,
+}
+
+/// Construct a Jacobi preconditioner.
+///
+/// The preconditioner is the diagonal of the matrix, which is required to
+/// have no zero elements.
+pub fn jacobi<T: Element>(matrix: &Compressed<T>) -> Result<Diagonal<T>> {
+    square!(matrix);
+    let mut values = vec![T::zero(); matrix.rows];
+    for (i, j, &value) in matrix.iter() {
+        if i == j {
+            values[i] = value;
+        }
+    }
+    if let Some(i) = values.iter().position(|value| value.is_zero()) {
+        raise!(Singular { pivot: i });
+    }
+    Ok(Diagonal::from_vec(matrix.rows, values))
+}
+
+impl<T> IncompleteCholesky<T>
+where
+    T: Element + Float,
+{
+    /// Perform the factorization.
+    ///
+    /// The matrix is assumed to be symmetric positive definite, and only its
+    /// lower triangle is referenced.
+    pub fn new(matrix: &Compressed<T>) -> Result<Self> {
+        square!(matrix);
+        let mut factor = matrix.to_variant(Variant::Row);
+        factor.retain(|i, j, _| j <= i);
+        let diagonal = diagonal(&factor)?;
+        let &mut Compressed {
+            ref mut values,
+            ref indices,
+            ref offsets,
+            ..
+        } = &mut factor;
+        for i in 0..diagonal.len() {
+            for p in offsets[i]..(diagonal[i] + 1) {
+                let j = indices[p];
+                let (mut k, mut l) = (offsets[i], offsets[j]);
+                let mut sum = values[p];
+                while k < p && l < diagonal[j] {
+                    if indices[k] < indices[l] {
+                        k += 1;
+                    } else if indices[l] < indices[k] {
+                        l += 1;
+                    } else {
+                        sum = sum - values[k] * values[l];
+                        k += 1;
+                        l += 1;
+                    }
+                }
+                if j < i {
+                    values[p] = sum / values[diagonal[j]];
+                } else if sum > <T as Element>::zero() {
+                    values[p] = sum.sqrt();
+                } else {
+                    raise!(NotPositiveDefinite { pivot: i });
+                }
+            }
+        }
+        Ok(IncompleteCholesky { factor })
+    }
+}
+
+impl<T> Preconditioner<T> for IncompleteCholesky<T>
+where
+    T: Element + Float,
+{
+    fn apply_inverse(&self, right: &[T], result: &mut [T]) {
+        let &Compressed {
+            rows: n,
+            ref values,
+            ref indices,
+            ref offsets,
+            ..
+        } = &self.factor;
+        result[..n].copy_from_slice(&right[..n]);
+        for i in 0..n {
+            let last = offsets[i + 1] - 1;
+            let mut sum = result[i];
+            for p in offsets[i]..last {
+                sum = sum - values[p] * result[indices[p]];
+            }
+            result[i] = sum / values[last];
+        }
+        for i in (0..n).rev() {
+            let last = offsets[i + 1] - 1;
+            result[i] = result[i] / values[last];
+            let x = result[i];
+            for p in offsets[i]..last {
+                let j = indices[p];
+                result[j] = result[j] - values[p] * x;
+            }
+        }
+    }
+}
+
+impl<T> IncompleteLU<T>
+where
+    T: Element + Float,
+{
+    /// Perform the factorization.
+    ///
+    /// Every diagonal element should be present in the sparsity pattern of the
+    /// matrix.
+    pub fn new(matrix: &Compressed<T>) -> Result<Self> {
+        square!(matrix);
+        let mut factors = matrix.to_variant(Variant::Row);
+        let diagonal = diagonal(&factors)?;
+        let n = diagonal.len();
+        let mut markers = vec![None; n];
+        {
+            let &mut Compressed {
+                ref mut values,
+                ref indices,
+                ref offsets,
+                ..
+            } = &mut factors;
+            for i in 0..n {
+                for p in offsets[i]..offsets[i + 1] {
+                    markers[indices[p]] = Some(p);
+                }
+                for p in offsets[i]..diagonal[i] {
+                    let k = indices[p];
+                    values[p] = values[p] / values[diagonal[k]];
+                    let factor = values[p];
+                    for q in (diagonal[k] + 1)..offsets[k + 1] {
+                        if let Some(position) = markers[indices[q]] {
+                            values[position] = values[position] - factor * values[q];
+                        }
+                    }
+                }
+                if Element::is_zero(&values[diagonal[i]]) {
+                    raise!(Singular { pivot: i });
+                }
+                for p in offsets[i]..offsets[i + 1] {
+                    markers[indices[p]] = None;
+                }
+            }
+        }
+        Ok(IncompleteLU { factors, diagonal })
+    }
+}
+
+impl<T> Preconditioner<T> for IncompleteLU<T>
+where
+    T: Element + Float,
+{
+    fn apply_inverse(&self, right: &[T], result: &mut [T]) {
+        let Compressed {
+            ref values,
+            ref indices,
+            ref offsets,
+            ..
+        } = self.factors;
+        let diagonal = &self.diagonal;
+        let n = diagonal.len();
+        result[..n].copy_from_slice(&right[..n]);
+        for i in 0..n {
+            let mut sum = result[i];
+            for p in offsets[i]..diagonal[i] {
+                sum = sum - values[p] * result[indices[p]];
+            }
+            result[i] = sum;
+        }
+        for i in (0..n).rev() {
+            let mut sum = result[i];
+            for p in (diagonal[i] + 1)..offsets[i + 1] {
+                sum = sum - values[p] * result[indices[p]];
+            }
+            result[i] = sum / values[diagonal[i]];
+        }
+    }
+}
+
+impl<T> Preconditioner<T> for Diagonal<T>
+where
+    T: Element + Float,
+{
+    fn apply_inverse(&self, right: &[T], result: &mut [T]) {
+        for ((result, &right), &value) in result.iter_mut().zip(right).zip(&self.values) {
+            *result = right / value;
+        }
+    }
+}
+
+/// Locate the diagonal elements of a matrix in the compressed-row variant.
+fn diagonal<T: Element>(matrix: &Compressed<T>) -> Result<Vec<usize>> {
+    let mut positions = Vec::with_capacity(matrix.rows);
+    for i in 0..matrix.rows {
+        let range = matrix.offsets[i]..matrix.offsets[i + 1];
+        match matrix.indices[range.clone()].binary_search(&i) {
+            Ok(k) => positions.push(range.start + k),
+            Err(_) => {
+                raise!(Singular { pivot: i });
+            }
+        }
+    }
+    Ok(positions)
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use format::compressed::tests::{laplacian, tridiagonal};
+    use iterative::tests::system;
+    use iterative::{self, IncompleteCholesky, IncompleteLU, Options};
+    use prelude::*;
+    use Error;
+
+    #[test]
+    fn jacobi() {
+        let mut matrix = tridiagonal(30, -1.0, 2.0, -1.0);
+        for (i, _, value) in matrix.iter_mut() {
+            *value *= (1 + i) as f64;
+        }
+        let matrix = &matrix + &matrix.transpose();
+        let preconditioner = iterative::jacobi(&matrix).unwrap();
+        assert_eq!(preconditioner[2], 12.0);
+        let (expected, right) = system(&matrix);
+        let mut solution = vec![0.0; 30];
+        let options = Options {
+            tolerance: 1e-12,
+            ..Options::default()
+        };
+        let report = iterative::conjugate_gradient(
+            &matrix,
+            &right,
+            &mut solution,
+            Some(&preconditioner),
+            &options,
+        )
+        .unwrap();
+        assert!(report.converged);
+        assert::close(&solution, &expected, 1e-8);
+        let matrix = Compressed::from(Diagonal::from_vec(2, vec![1.0, 0.0]));
+        assert_eq!(iterative::jacobi(&matrix), Err(Error::Singular { pivot: 1 }));
+    }
+
+    #[test]
+    fn incomplete_cholesky() {
+        let matrix = tridiagonal(20, -1.0, 2.0, -1.0);
+        let (expected, right) = system(&matrix);
+        let preconditioner = IncompleteCholesky::new(&matrix).unwrap();
+        let mut result = vec![0.0; 20];
+        preconditioner.apply_inverse(&right, &mut result);
+        assert::close(&result, &expected, 1e-12);
+
+        let matrix = laplacian(10);
+        let (expected, right) = system(&matrix);
+        let preconditioner = IncompleteCholesky::new(&matrix).unwrap();
+        let options = Options {
+            tolerance: 1e-10,
+            ..Options::default()
+        };
+        let mut solution = vec![0.0; 100];
+        let plain =
+            iterative::conjugate_gradient(&matrix, &right, &mut solution, None, &options).unwrap();
+        let mut solution = vec![0.0; 100];
+        let report = iterative::conjugate_gradient(
+            &matrix,
+            &right,
+            &mut solution,
+            Some(&preconditioner),
+            &options,
+        )
+        .unwrap();
+        assert!(report.converged);
+        assert!(report.iterations < plain.iterations);
+        assert::close(&solution, &expected, 1e-8);
+    }
+
+    #[test]
+    fn incomplete_cholesky_indefinite() {
+        let matrix = tridiagonal(3, -2.0, 1.0, -2.0);
+        assert_eq!(
+            IncompleteCholesky::new(&matrix),
+            Err(Error::NotPositiveDefinite { pivot: 1 })
+        );
+    }
+
+    #[test]
+    fn incomplete_lu() {
+        let matrix = tridiagonal(20, -1.5, 2.5, -0.5);
+        let (expected, right) = system(&matrix);
+        let preconditioner = IncompleteLU::new(&matrix).unwrap();
+        let mut result = vec![0.0; 20];
+        preconditioner.apply_inverse(&right, &mut result);
+        assert::close(&result, &expected, 1e-12);
+
+        let mut matrix = laplacian(10);
+        for (i, j, value) in matrix.iter_mut() {
+            if j == i + 1 {
+                *value = -0.5;
+            }
+        }
+        let (expected, right) = system(&matrix);
+        let preconditioner = IncompleteLU::new(&matrix).unwrap();
+        let options = Options {
+            tolerance: 1e-10,
+            ..Options::default()
+        };
+        let mut solution = vec![0.0; 100];
+        let plain = iterative::gmres(&matrix, &right, &mut solution, None, &options).unwrap();
+        let mut solution = vec![0.0; 100];
+        let report = iterative::gmres(
+            &matrix,
+            &right,
+            &mut solution,
+            Some(&preconditioner),
+            &options,
+        )
+        .unwrap();
+        assert!(report.converged);
+        assert!(report.iterations < plain.iterations);
+        assert::close(&solution, &expected, 1e-8);
+        let mut solution = vec![0.0; 100];
+        let report = iterative::bicgstab(
+            &matrix,
+            &right,
+            &mut solution,
+            Some(&preconditioner),
+            &options,
+        )
+        .unwrap();
+        assert!(report.converged);
+        assert::close(&solution, &expected, 1e-8);
+    }
+
+    #[test]
+    fn incomplete_lu_missing_diagonal() {
+        let matrix = Compressed::from(Conventional::from_vec(2, vec![1.0, 1.0, 1.0, 0.0]));
+        assert_eq!(
+            IncompleteLU::new(&matrix).map(|_| ()),
+            Err(Error::Singular { pivot: 1 })
+        );
+    }
+}
@@ -11,6 +11,8 @@
 //!
 //! * [Conventional][conventional], suitable for dense matrices;
 //!
+//! * [Coordinate][coordinate], suitable for assembling sparse matrices;
+//!
 //! * [Diagonal][diagonal], suitable for diagonal matrices; and
 //!
 //! * [Packed][packed], suitable for symmetric, Hermitian, and triangular
@@ -42,6 +44,7 @@
 //! [banded]: format/banded/index.html
 //! [compressed]: format/compressed/index.html
 //! [conventional]: format/conventional/index.html
+//! [coordinate]: format/coordinate/index.html
 //! [diagonal]: format/diagonal/index.html
 //! [packed]: format/packed/index.html
 
@@ -138,31 +141,121 @@ macro_rules! matrix {
 
 /// An error.
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct Error(String);
+pub enum Error {
+    /// The dimensions of two operands do not conform.
+    DimensionMismatch {
+        /// The number of rows and columns of the left operand.
+        left: (usize, usize),
+        /// The number of rows and columns of the right operand.
+        right: (usize, usize),
+    },
+    /// A square matrix is expected.
+    NotSquare {
+        /// The number of rows.
+        rows: usize,
+        /// The number of columns.
+        columns: usize,
+    },
+    /// The matrix is singular.
+    Singular {
+        /// The column where a zero pivot was encountered.
+        pivot: usize,
+    },
+    /// The matrix is not positive definite.
+    NotPositiveDefinite {
+        /// The column where a nonpositive pivot was encountered.
+        pivot: usize,
+    },
+    /// An iterative algorithm failed to converge.
+    NonConvergence {
+        /// The number of iterations performed if known.
+        iterations: Option<usize>,
+    },
+    /// A backend routine rejected its arguments.
+    InvalidArgument {
+        /// The status reported by LAPACK.
+        info: i32,
+    },
+    /// The input could not be parsed.
+    Parse {
+        /// The line, starting from one.
+        line: usize,
+        /// The column, starting from one.
+        column: usize,
+        /// The description of the problem.
+        message: String,
+    },
+    /// A matrix or an argument violates an invariant.
+    Validation(String),
+}
 
 /// A result.
 pub type Result<T> = std::result::Result<T, Error>;
 
-#[cfg(feature = "acceleration")]
 macro_rules! raise(
+    ($kind:ident { $($field:tt)* }) => (
+        return Err(::Error::$kind { $($field)* });
+    );
     ($message:expr) => (
-        return Err(::Error($message.to_string()));
+        return Err(::Error::Validation($message.to_string()));
+    );
+    ($($argument:tt)*) => (
+        return Err(::Error::Validation(format!($($argument)*)));
     );
 );
 
+macro_rules! square(
+    ($matrix:expr) => (
+        square!($matrix.rows, $matrix.columns)
+    );
+    ($rows:expr, $columns:expr) => ({
+        let (rows, columns) = ($rows, $columns);
+        if rows != columns {
+            raise!(NotSquare { rows, columns });
+        }
+        rows
+    });
+);
+
 impl fmt::Display for Error {
-    #[inline]
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        self.0.fmt(formatter)
+        match *self {
+            Error::DimensionMismatch { left, right } => write!(
+                formatter,
+                "the dimensions of a {} × {} matrix and a {} × {} matrix do not conform",
+                left.0, left.1, right.0, right.1
+            ),
+            Error::NotSquare { rows, columns } => write!(
+                formatter,
+                "expected a square matrix but got {} × {}",
+                rows, columns
+            ),
+            Error::Singular { pivot } => {
+                write!(formatter, "encountered a zero pivot in column {}", pivot)
+            }
+            Error::NotPositiveDefinite { pivot } => write!(
+                formatter,
+                "encountered a nonpositive pivot in column {}",
+                pivot
+            ),
+            Error::NonConvergence {
+                iterations: Some(iterations),
+            } => write!(formatter, "failed to converge in {} iterations", iterations),
+            Error::NonConvergence { iterations: None } => write!(formatter, "failed to converge"),
+            Error::InvalidArgument { info } => {
+                write!(formatter, "encountered invalid arguments (info = {})", info)
+            }
+            Error::Parse {
+                line,
+                column,
+                ref message,
+            } => write!(formatter, "{} at line {}, column {}", message, line, column),
+            Error::Validation(ref message) => message.fmt(formatter),
+        }
     }
 }
 
-impl error::Error for Error {
-    #[inline]
-    fn description(&self) -> &str {
-        &self.0
-    }
-}
+impl error::Error for Error {}
 
 mod element;
 mod position;
@@ -174,5 +267,6 @@ pub use size::Size;
 
 pub mod decomposition;
 pub mod format;
+pub mod iterative;
 pub mod operation;
 pub mod prelude;
@@ -1,5 +1,31 @@
 //! Basic operations.
 
+use {Element, Result};
+
+/// The determinant.
+pub trait Determinant<T: Element> {
+    /// Compute the determinant.
+    fn determinant(&self) -> Result<T>;
+}
+
+/// The inverse.
+pub trait Inverse: Sized {
+    /// Compute the inverse.
+    fn inverse(&self) -> Result<Self>;
+}
+
+/// A least-squares solution of a system of linear equations.
+pub trait LeastSquares<T: Element, Right: ?Sized, Output> {
+    /// Solve the system with the receiver as the coefficient matrix.
+    ///
+    /// The solution minimizes the Euclidean norm of the residual and, among
+    /// all such solutions, has the smallest Euclidean norm, which covers
+    /// overdetermined, underdetermined, and rank-deficient systems. The
+    /// function also returns the norms of the residuals, one for each
+    /// right-hand side, and the effective rank of the coefficient matrix.
+    fn solve_least_squares(&self, right: &Right) -> Result<(Output, Vec<T>, usize)>;
+}
+
 /// A multiplication.
 pub trait Multiply<Right: ?Sized, Output> {
     /// Perform the multiplication.
@@ -18,12 +44,29 @@ pub trait MultiplySelf<Right: ?Sized> {
     fn multiply_self(&mut self, &Right);
 }
 
+/// The rank.
+pub trait Rank<T: Element> {
+    /// Compute the rank.
+    ///
+    /// The rank is the number of singular values or their estimates, such as
+    /// the diagonal elements of the upper-triangular factor of the QR
+    /// decomposition with column pivoting, whose magnitudes exceed the
+    /// tolerance.
+    fn rank(&self, tolerance: T) -> usize;
+}
+
 /// A scaling that overwrites the receiver with the result.
 pub trait ScaleSelf<T> {
     /// Perform the scaling.
     fn scale_self(&mut self, T);
 }
 
+/// A solution of a system of linear equations.
+pub trait Solve<Right: ?Sized, Output> {
+    /// Solve the system with the receiver as the coefficient matrix.
+    fn solve(&self, right: &Right) -> Result<Output>;
+}
+
 /// The transpose.
 pub trait Transpose {
     /// Perform the transpose.
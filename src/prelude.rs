@@ -8,20 +8,33 @@ pub use Size;
 pub use format::banded;
 pub use format::compressed;
 pub use format::conventional;
+pub use format::coordinate;
 pub use format::diagonal;
 pub use format::packed;
 
 pub use format::banded::Banded;
 pub use format::compressed::Compressed;
 pub use format::conventional::Conventional;
+pub use format::coordinate::Coordinate;
 pub use format::diagonal::Diagonal;
 pub use format::packed::Packed;
 
+pub use operation::Determinant;
+pub use operation::Inverse;
+pub use operation::LeastSquares;
 pub use operation::Multiply;
 pub use operation::MultiplyInto;
 pub use operation::MultiplySelf;
+pub use operation::Rank;
 pub use operation::ScaleSelf;
+pub use operation::Solve;
 pub use operation::Transpose;
 
+pub use iterative::Preconditioner;
+
+pub use decomposition::Cholesky;
+pub use decomposition::GeneralEigen;
+pub use decomposition::LU;
+pub use decomposition::QR;
 pub use decomposition::SingularValue;
 pub use decomposition::SymmetricEigen;